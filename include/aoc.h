@@ -0,0 +1,32 @@
+/* Generated by `cargo run --bin aoc -- ffi-header`, do not edit. */
+
+#ifndef AOC_H
+#define AOC_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#define AOC_OK 0 /* ok */
+#define AOC_UNKNOWN_DAY 1 /* no solver for this day */
+#define AOC_UNKNOWN_PART 2 /* part must be 1 or 2 */
+#define AOC_UNSOLVED 3 /* part isn't solved yet */
+#define AOC_INVALID_UTF8 4 /* input isn't valid UTF-8 */
+#define AOC_INVALID_INPUT 5 /* solver rejected the input */
+#define AOC_BUFFER_TOO_SMALL 6 /* output buffer too small */
+#define AOC_NULL_POINTER 7 /* unexpected NULL pointer */
+#define AOC_UNKNOWN_YEAR 8 /* no solvers for this year */
+#define AOC_INTERNAL_ERROR 9 /* solver failed on a valid input */
+
+/*
+ * Solves `part` of `day` in `year` for the `input_len` bytes at `input`. The answer is written to
+ * `out` as a NUL-terminated string, `out_len` holds the capacity of `out` on entry and the length
+ * of the answer without the NUL byte on return. `input` may be NULL if `input_len` is 0. Returns
+ * one of the status codes above.
+ */
+int32_t aoc_solve(uint16_t year, uint8_t day, uint8_t part, const uint8_t *input, size_t input_len,
+                  char *out, size_t *out_len);
+
+/* Returns a static description of a status code. */
+const char *aoc_status_message(int32_t status);
+
+#endif
@@ -0,0 +1,151 @@
+//! A global allocator wrapping [`System`] which keeps track of the bytes allocated per thread.
+//!
+//! Binaries opt in by installing it:
+//!
+//! ```ignore
+//! #[global_allocator]
+//! static ALLOCATOR: aoc::alloc::CountingAllocator = aoc::alloc::CountingAllocator;
+//! ```
+//!
+//! Memory freed on a different thread than it was allocated on is attributed to the freeing
+//! thread, which is good enough for solvers running on a single worker thread. An allocation that
+//! would take a thread over the limit set with [`reset`] stops the thread, see
+//! [`cancel::stop_at_memory_limit`].
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+use crate::cancel;
+
+pub struct CountingAllocator;
+
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+
+#[derive(Debug, Clone, Copy)]
+struct ThreadStats {
+    current: isize,
+    peak: isize,
+    limit: Option<usize>,
+    allocations: usize,
+    allocated_bytes: usize,
+}
+
+thread_local! {
+    // Must not allocate on first access, otherwise allocating would recurse into the allocator.
+    static STATS: Cell<ThreadStats> = const {
+        Cell::new(ThreadStats {
+            current: 0,
+            peak: 0,
+            limit: None,
+            allocations: 0,
+            allocated_bytes: 0,
+        })
+    };
+}
+
+/// Records a change of `delta` bytes in use, `allocated` is the size of a new allocation if the
+/// change comes from one.
+fn record(delta: isize, allocated: Option<usize>) {
+    if !INSTALLED.load(Ordering::Relaxed) {
+        INSTALLED.store(true, Ordering::Relaxed);
+    }
+    // Fails during thread teardown, allocations at that point don't matter anymore.
+    let _ = STATS.try_with(|stats| {
+        let mut s = stats.get();
+        s.current += delta;
+        s.peak = s.peak.max(s.current);
+        if let Some(size) = allocated {
+            s.allocations += 1;
+            s.allocated_bytes += size;
+        }
+        stats.set(s);
+    });
+}
+
+/// Whether `size` more bytes in use take the current thread over its limit.
+fn exceeds_limit(size: usize) -> bool {
+    STATS
+        .try_with(|stats| {
+            let s = stats.get();
+            s.limit
+                .is_some_and(|limit| (s.current.max(0) as usize).saturating_add(size) > limit)
+        })
+        .unwrap_or(false)
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        if exceeds_limit(layout.size()) {
+            cancel::stop_at_memory_limit();
+        }
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record(layout.size() as isize, Some(layout.size()));
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        if exceeds_limit(layout.size()) {
+            cancel::stop_at_memory_limit();
+        }
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record(layout.size() as isize, Some(layout.size()));
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record(-(layout.size() as isize), None);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        if exceeds_limit(new_size.saturating_sub(layout.size())) {
+            cancel::stop_at_memory_limit();
+        }
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            record(new_size as isize - layout.size() as isize, Some(new_size));
+        }
+        new_ptr
+    }
+}
+
+/// Whether the running binary uses [`CountingAllocator`]. Only reliable after the first
+/// allocation, which always happened by the time `main` runs.
+pub fn is_installed() -> bool {
+    INSTALLED.load(Ordering::Relaxed)
+}
+
+/// Starts counting from zero on the current thread and sets its memory limit in bytes.
+pub fn reset(limit: Option<usize>) {
+    STATS.with(|stats| {
+        stats.set(ThreadStats {
+            current: 0,
+            peak: 0,
+            limit,
+            allocations: 0,
+            allocated_bytes: 0,
+        })
+    });
+}
+
+/// The maximum number of bytes allocated at the same time on the current thread since the last
+/// [`reset`].
+pub fn peak() -> usize {
+    STATS.with(|stats| stats.get().peak.max(0) as usize)
+}
+
+/// Number of allocations and their total size in bytes on the current thread since the last
+/// [`reset`], a `realloc` counts as a new allocation of its new size.
+pub fn allocations() -> (usize, usize) {
+    STATS.with(|stats| {
+        let s = stats.get();
+        (s.allocations, s.allocated_bytes)
+    })
+}
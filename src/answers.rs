@@ -0,0 +1,128 @@
+//! Registry of the known answers for the puzzle inputs of a year, kept next to them in
+//! `inputs/<year>/answers.txt`. Every line holds the day, the part and the answer separated by
+//! whitespace, lines starting with `#` are comments.
+
+use std::{
+    collections::HashMap,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{input::invalid_data, solution::Part};
+
+pub fn path(year: u16) -> PathBuf {
+    PathBuf::from(format!("inputs/{}/answers.txt", year))
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Answers(HashMap<(u8, Part), String>);
+
+impl Answers {
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let mut answers = HashMap::new();
+        for (index, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let fields = line.split_whitespace().collect::<Vec<_>>();
+            let [day, part, answer] = fields[..] else {
+                return Err(format!(
+                    "line {}: expected <day> <part> <answer>",
+                    index + 1
+                ));
+            };
+            let day = day
+                .parse()
+                .map_err(|_| format!("line {}: invalid day '{}'", index + 1, day))?;
+            let part = part
+                .parse()
+                .ok()
+                .and_then(Part::from_number)
+                .ok_or_else(|| format!("line {}: invalid part '{}'", index + 1, part))?;
+            answers.insert((day, part), answer.to_string());
+        }
+        Ok(Self(answers))
+    }
+
+    /// Loads the registry at `path`, a missing file gives an empty registry.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(s) => {
+                Self::parse(&s).map_err(|e| invalid_data(format!("{}: {}", path.display(), e)))
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&str> {
+        self.0.get(&(day, part)).map(String::as_str)
+    }
+}
+
+/// Asserts that `solution` gives the answers listed in `answers` for `example`, like the
+/// fixtures written by `aoc examples`. Parts without a listed answer aren't checked.
+#[cfg(test)]
+pub(crate) fn assert_example(
+    solution: &dyn crate::solution::Solution,
+    example: &str,
+    answers: &str,
+) {
+    let answers = Answers::parse(answers).unwrap();
+    let day = solution.day();
+    let known: Vec<_> = Part::ALL
+        .into_iter()
+        .filter_map(|part| Some((part, answers.get(day, part)?)))
+        .collect();
+    assert!(!known.is_empty(), "no answers for day {}", day);
+    for (part, answer) in known {
+        assert_eq!(
+            solution.solve(part, example).as_deref(),
+            Some(answer),
+            "part {}",
+            part
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse("# day part answer\n01 1 142\n\n1 2 281\n").unwrap();
+        assert_eq!(answers.get(1, Part::One), Some("142"));
+        assert_eq!(answers.get(1, Part::Two), Some("281"));
+        assert_eq!(answers.get(2, Part::One), None);
+
+        assert!(Answers::parse("01 3 142").is_err());
+        assert!(Answers::parse("01 1").is_err());
+    }
+
+    struct Length;
+
+    impl crate::solution::Solution for Length {
+        fn day(&self) -> u8 {
+            1
+        }
+
+        fn part_1(&self, input: &str) -> String {
+            input.len().to_string()
+        }
+
+        fn part_2(&self, _input: &str) -> Option<String> {
+            None
+        }
+    }
+
+    #[test]
+    fn test_assert_example() {
+        // The second part isn't solved and has no answer, so only the first one is checked.
+        assert_example(&Length, "abc", "01 1 3\n");
+        let differs = std::panic::catch_unwind(|| assert_example(&Length, "abc", "01 1 4\n"));
+        assert!(differs.is_err());
+    }
+}
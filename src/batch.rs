@@ -0,0 +1,174 @@
+//! Solves every input found in a directory tree laid out like `inputs/<user>/dayNN.txt` with the
+//! solvers of a single year.
+
+use std::{sync::Arc, time::Duration};
+
+use serde_json::json;
+
+use crate::{
+    input::{self, InputFile},
+    runner::{run_part, Limits, Outcome},
+    solution::Part,
+    years::Year,
+};
+
+#[derive(Debug, Clone)]
+pub struct Cell {
+    pub user: String,
+    pub day: u8,
+    /// `None` if the file couldn't be read, which then has a single cell instead of one per part.
+    pub part: Option<Part>,
+    /// The outcome of the part, or why the file couldn't be read.
+    pub outcome: Result<Outcome, String>,
+    pub elapsed: Duration,
+    pub peak_bytes: Option<usize>,
+}
+
+impl Cell {
+    pub fn is_failure(&self) -> bool {
+        matches!(
+            self.outcome,
+            Ok(Outcome::Panicked(_) | Outcome::TimedOut | Outcome::MemoryLimitExceeded) | Err(_)
+        )
+    }
+
+    fn status(&self) -> &'static str {
+        match self.outcome {
+            Ok(Outcome::Solved(_)) => "ok",
+            Ok(Outcome::Unsolved) => "unsolved",
+            Ok(Outcome::Panicked(_)) => "panicked",
+            Ok(Outcome::TimedOut) => "timeout",
+            Ok(Outcome::MemoryLimitExceeded) => "memory",
+            Err(_) => "unreadable",
+        }
+    }
+
+    /// The answer, or the panic message for failed runs and the error for unreadable files.
+    fn answer(&self) -> &str {
+        match &self.outcome {
+            Ok(Outcome::Solved(answer)) => answer,
+            Ok(Outcome::Panicked(message)) => message,
+            Ok(Outcome::Unsolved | Outcome::TimedOut | Outcome::MemoryLimitExceeded) => "",
+            Err(e) => e,
+        }
+    }
+}
+
+/// Runs all parts of every file with a solver in `year`. Files for unsolved days are skipped, files
+/// that can't be read get a failed cell and don't stop the others.
+pub fn run(year: &Year, files: &[InputFile], limits: &Limits) -> Vec<Cell> {
+    let mut cells = vec![];
+
+    for file in files {
+        let Some(solution) = year.get(file.day) else {
+            continue;
+        };
+        let input: Arc<str> = match input::read(&file.path) {
+            Ok(input) => input.into(),
+            Err(e) => {
+                cells.push(Cell {
+                    user: file.user.clone(),
+                    day: file.day,
+                    part: None,
+                    outcome: Err(e.to_string()),
+                    elapsed: Duration::ZERO,
+                    peak_bytes: None,
+                });
+                continue;
+            }
+        };
+
+        for part in Part::ALL {
+            let run = run_part(solution, part, input.clone(), limits);
+            if run.outcome == Outcome::Unsolved {
+                continue;
+            }
+            cells.push(Cell {
+                user: file.user.clone(),
+                day: file.day,
+                part: Some(part),
+                outcome: Ok(run.outcome),
+                elapsed: run.elapsed,
+                peak_bytes: run.peak_bytes,
+            });
+        }
+    }
+
+    cells
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+pub fn to_csv(cells: &[Cell]) -> String {
+    let mut result = String::from("user,day,part,status,answer,time_ms,peak_bytes\n");
+
+    for cell in cells {
+        result.push_str(&format!(
+            "{},{},{},{},{},{:.3},{}\n",
+            csv_field(&cell.user),
+            cell.day,
+            cell.part.map(|p| p.to_string()).unwrap_or_default(),
+            cell.status(),
+            csv_field(cell.answer()),
+            cell.elapsed.as_secs_f64() * 1000.0,
+            cell.peak_bytes.map(|b| b.to_string()).unwrap_or_default()
+        ));
+    }
+
+    result
+}
+
+pub fn to_json(cells: &[Cell]) -> serde_json::Value {
+    cells
+        .iter()
+        .map(|cell| {
+            json!({
+                "user": cell.user,
+                "day": cell.day,
+                "part": cell.part.map(|p| p.number()),
+                "status": cell.status(),
+                "answer": cell.answer(),
+                "time_ms": cell.elapsed.as_secs_f64() * 1000.0,
+                "peak_bytes": cell.peak_bytes,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_csv_field() {
+        assert_eq!(csv_field("42"), "42");
+        assert_eq!(csv_field("index out of bounds, len 3"), "\"index out of bounds, len 3\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+
+    #[test]
+    fn test_unreadable_files_dont_stop_the_batch() {
+        let year = crate::years::latest();
+        let Some(solution) = year.days.first() else {
+            return;
+        };
+        let file = |user: &str| InputFile {
+            user: user.to_string(),
+            day: solution.day(),
+            path: format!("does/not/exist/{}.txt", user).into(),
+        };
+        let cells = run(year, &[file("a"), file("b")], &Limits::default());
+
+        assert_eq!(cells.len(), 2);
+        assert!(cells.iter().all(|c| c.is_failure() && c.part.is_none()));
+        assert_eq!(cells[1].user, "b");
+        assert_eq!(cells[1].status(), "unreadable");
+        assert!(to_csv(&cells).lines().nth(1).unwrap().starts_with("a,"));
+    }
+}
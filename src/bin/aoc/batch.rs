@@ -0,0 +1,82 @@
+use std::{panic, path::Path, time::Duration};
+
+use aoc::{batch, input, runner::Limits, years};
+
+pub fn run(args: &[String]) -> Result<(), String> {
+    let mut dir = None;
+    let mut format = "csv".to_string();
+    let mut limits = Limits::default();
+    let mut year = years::latest();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format" => {
+                format = args.next().ok_or("--format needs a value")?.clone();
+            }
+            "--time-limit" => {
+                limits.time = args
+                    .next()
+                    .ok_or("--time-limit needs a value")?
+                    .parse()
+                    .ok()
+                    .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+                    .ok_or("--time-limit expects seconds")?;
+            }
+            "--year" => {
+                let value = args.next().ok_or("--year needs a value")?;
+                year = value
+                    .parse()
+                    .ok()
+                    .and_then(years::get)
+                    .ok_or_else(|| format!("no solvers for year '{}'", value))?;
+            }
+            "--memory-limit" => {
+                let bytes = args
+                    .next()
+                    .ok_or("--memory-limit needs a value")?
+                    .parse::<usize>()
+                    .ok()
+                    .and_then(|mebibytes| mebibytes.checked_mul(1 << 20))
+                    .ok_or("--memory-limit expects MiB")?;
+                limits.memory = Some(bytes);
+            }
+            _ if dir.is_none() => dir = Some(arg),
+            _ => return Err(format!("unexpected argument '{}'", arg)),
+        }
+    }
+    let dir = dir.ok_or("missing input directory")?;
+    if format != "csv" && format != "json" {
+        return Err(format!("unknown format '{}'", format));
+    }
+
+    // Panics are reported in the summary below, the default hook would only clutter stderr.
+    panic::set_hook(Box::new(|_| {}));
+
+    let files = input::discover(Path::new(dir)).map_err(|e| e.to_string())?;
+    let cells = batch::run(year, &files, &limits);
+
+    match format.as_str() {
+        "csv" => print!("{}", batch::to_csv(&cells)),
+        _ => println!("{:#}", batch::to_json(&cells)),
+    }
+
+    let failures = cells.iter().filter(|c| c.is_failure()).collect::<Vec<_>>();
+    if !failures.is_empty() {
+        eprintln!("{} of {} runs failed:", failures.len(), cells.len());
+        for cell in failures {
+            let part = cell
+                .part
+                .map(|p| format!(" part {}", p))
+                .unwrap_or_default();
+            match &cell.outcome {
+                Ok(outcome) => {
+                    eprintln!("  {} day {:02}{}: {}", cell.user, cell.day, part, outcome)
+                }
+                Err(e) => eprintln!("  {} day {:02}: {}", cell.user, cell.day, e),
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,89 @@
+use std::{
+    fs::{self, create_dir_all, read_to_string},
+    path::Path,
+};
+
+use aoc::{examples, years};
+
+/// Writes `contents` unless `path` already has contents of its own, `aoc new` leaves empty
+/// placeholders behind.
+fn write(path: &Path, contents: &str, force: bool) -> Result<(), String> {
+    let existing = read_to_string(path).unwrap_or_default();
+    if !existing.trim().is_empty() && !force {
+        return Err(format!(
+            "{} exists already, pass --force to overwrite it",
+            path.display()
+        ));
+    }
+    fs::write(path, contents).map_err(|e| format!("couldn't write {}: {}", path.display(), e))?;
+    println!("wrote {}", path.display());
+    Ok(())
+}
+
+pub fn run(args: &[String]) -> Result<(), String> {
+    let force = args.iter().any(|a| a == "--force");
+    let args = args.iter().filter(|a| *a != "--force").collect::<Vec<_>>();
+    let [year, day, page] = args[..] else {
+        return Err("expected a year, a day and a saved puzzle page".to_string());
+    };
+    let year = year
+        .parse()
+        .ok()
+        .and_then(years::get)
+        .ok_or_else(|| format!("no solvers for year '{}'", year))?
+        .year;
+    let day: u8 = day
+        .parse()
+        .ok()
+        .filter(|d| (1..=25).contains(d))
+        .ok_or_else(|| format!("'{}' isn't a day between 1 and 25", day))?;
+
+    let page = read_to_string(page).map_err(|e| format!("{}: {}", page, e))?;
+    let examples = examples::extract(&page);
+    if examples.blocks.is_empty() {
+        return Err("no example blocks found".to_string());
+    }
+
+    let dir = format!("fixtures/{}/day{:02}", year, day);
+    create_dir_all(&dir).map_err(|e| e.to_string())?;
+
+    // The first block is usually the example both parts are explained with. Its answers go to
+    // `answers.txt`, the ones of other blocks next to them in `answers-<n>.txt`.
+    for (index, block) in examples.blocks.iter().enumerate() {
+        let suffix = match index {
+            0 => String::new(),
+            _ => format!("-{}", index + 1),
+        };
+        write(
+            &Path::new(&dir).join(format!("example{}.txt", suffix)),
+            block,
+            force,
+        )?;
+
+        let block_answers = examples
+            .answers
+            .iter()
+            .filter(|(_, block, _)| *block == index)
+            .collect::<Vec<_>>();
+        if index > 0 && block_answers.is_empty() {
+            continue;
+        }
+        let mut answers = format!(
+            "# Answers for example{}.txt: <day> <part> <answer>\n",
+            suffix
+        );
+        for (part, _, answer) in block_answers {
+            answers += &format!("{:02} {} {}\n", day, part, answer);
+        }
+        write(
+            &Path::new(&dir).join(format!("answers{}.txt", suffix)),
+            &answers,
+            force,
+        )?;
+    }
+
+    if examples.answers.is_empty() {
+        eprintln!("warning: no emphasised answers found, fill in answers.txt by hand");
+    }
+    Ok(())
+}
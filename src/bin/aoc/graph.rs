@@ -0,0 +1,27 @@
+use aoc::{input, years};
+
+/// Prints the graph a day's input describes in DOT format, e.g. for `| dot -Tsvg > day.svg`.
+pub fn run(args: &[String]) -> Result<(), String> {
+    let [year, day] = args else {
+        return Err("expected a year and a day".to_string());
+    };
+    let year = year
+        .parse()
+        .ok()
+        .and_then(years::get)
+        .ok_or_else(|| format!("no solvers for year '{}'", year))?;
+    let solution = day
+        .parse()
+        .ok()
+        .and_then(|day| year.get(day))
+        .ok_or_else(|| format!("no solver for day '{}' of {}", day, year.year))?;
+
+    let path = input::path(year.year, solution.day());
+    let input = input::read(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+
+    let graph = solution
+        .graph(&input)
+        .ok_or_else(|| format!("day {} of {} has no graph", solution.day(), year.year))?;
+    print!("{}", graph.dot());
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use std::{fs, path::PathBuf};
+
+use aoc::{
+    encryption::{self, Key},
+    input,
+};
+
+fn load_key() -> Result<Key, String> {
+    Key::load().map_err(|e| e.to_string())?.ok_or_else(|| {
+        format!(
+            "no key configured, set {} or run `aoc inputs keygen`",
+            encryption::KEY_VAR
+        )
+    })
+}
+
+/// The given files, or every input below `inputs/` if none are given.
+fn files(args: &[String]) -> Result<Vec<PathBuf>, String> {
+    if !args.is_empty() {
+        return Ok(args.iter().map(PathBuf::from).collect());
+    }
+    let files = input::discover("inputs".as_ref()).map_err(|e| e.to_string())?;
+    Ok(files.into_iter().map(|f| f.path).collect())
+}
+
+fn keygen() -> Result<(), String> {
+    let path = Key::key_file();
+    if path.exists() {
+        return Err(format!("{} already exists", path.display()));
+    }
+    fs::write(&path, Key::generate().to_hex() + "\n").map_err(|e| e.to_string())?;
+    println!(
+        "wrote a new key to {}, keep it out of version control",
+        path.display()
+    );
+    Ok(())
+}
+
+fn encrypt(args: &[String]) -> Result<(), String> {
+    let key = load_key()?;
+    for path in files(args)? {
+        let data = fs::read(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        if input::is_encrypted(&data) {
+            continue;
+        }
+        fs::write(&path, encryption::encrypt(&key, &data)).map_err(|e| e.to_string())?;
+        println!("encrypted {}", path.display());
+    }
+    Ok(())
+}
+
+fn decrypt(args: &[String]) -> Result<(), String> {
+    let key = load_key()?;
+    for path in files(args)? {
+        let data = fs::read(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        if !input::is_encrypted(&data) {
+            continue;
+        }
+        let plaintext =
+            encryption::decrypt(&key, &data).map_err(|e| format!("{}: {}", path.display(), e))?;
+        fs::write(&path, plaintext).map_err(|e| e.to_string())?;
+        println!("decrypted {}", path.display());
+    }
+    Ok(())
+}
+
+pub fn run(args: &[String]) -> Result<(), String> {
+    match args.first().map(String::as_str) {
+        Some("encrypt") => encrypt(&args[1..]),
+        Some("decrypt") => decrypt(&args[1..]),
+        Some("keygen") => keygen(),
+        _ => Err("expected encrypt, decrypt or keygen".to_string()),
+    }
+}
@@ -0,0 +1,62 @@
+use std::{env, process::exit};
+
+use aoc::alloc::CountingAllocator;
+
+#[cfg(feature = "batch")]
+mod batch;
+mod examples;
+mod graph;
+#[cfg(feature = "encryption")]
+mod inputs;
+mod new;
+mod profile;
+mod report;
+mod run;
+mod watch;
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+const USAGE: &str = "usage:
+  aoc run <year> <day> [--explain [--format text|json]]
+  aoc run <year> all [--time-limit <seconds>] [--threads <n>]
+  aoc watch [<year>] <day>
+  aoc batch <dir> [--year <year>] [--format csv|json] [--time-limit <seconds>]
+            [--memory-limit <MiB>]
+  aoc new <year> <day>
+  aoc examples <year> <day> <saved puzzle page> [--force]
+  aoc profile <year> [<day>...]
+  aoc graph <year> <day>
+  aoc report [<year>] [--output <file>] [--time-limit <seconds>]
+  aoc inputs encrypt|decrypt [<file>...]
+  aoc inputs keygen
+  aoc ffi-header";
+
+fn main() {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+
+    let result = match args.first().map(String::as_str) {
+        Some("run") => run::run(&args[1..]),
+        Some("watch") => watch::run(&args[1..]),
+        #[cfg(feature = "batch")]
+        Some("batch") => batch::run(&args[1..]),
+        Some("new") => new::run(&args[1..]),
+        Some("examples") => examples::run(&args[1..]),
+        Some("profile") => profile::run(&args[1..]),
+        Some("graph") => graph::run(&args[1..]),
+        Some("report") => report::run(&args[1..]),
+        #[cfg(feature = "encryption")]
+        Some("inputs") => inputs::run(&args[1..]),
+        #[cfg(feature = "ffi")]
+        Some("ffi-header") => {
+            print!("{}", aoc::ffi::header());
+            Ok(())
+        }
+        _ => Err("unknown command".to_string()),
+    };
+
+    if let Err(e) = result {
+        eprintln!("{}\n{}", e, USAGE);
+        exit(2);
+    }
+}
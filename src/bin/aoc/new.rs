@@ -0,0 +1,236 @@
+use std::{
+    fs::{self, create_dir_all, read_to_string},
+    path::Path,
+};
+
+use aoc::{input, years};
+
+const TEMPLATE: &str = include_str!("../../../templates/day.rs");
+
+/// Parses the number following a leading `day`, e.g. `day05::Day05` gives 5.
+fn day_of(s: &str) -> Option<u8> {
+    let digits = s
+        .strip_prefix("day")?
+        .chars()
+        .take_while(|c| c.is_ascii_digit())
+        .collect::<String>();
+    digits.parse().ok()
+}
+
+/// Finds where a line for `day` has to go to keep the lines recognised by `anchor` sorted.
+/// Returns the index of the first anchored line with a larger day and `true`, or the index of the
+/// last anchored line and `false` if all days are smaller. Fails if no line is anchored, as for a
+/// year without any days.
+fn position(
+    lines: &[String],
+    day: u8,
+    anchor: impl Fn(&str) -> Option<u8>,
+) -> Result<(usize, bool), String> {
+    let mut last = None;
+    for (index, line) in lines.iter().enumerate() {
+        match anchor(line.trim()) {
+            Some(d) if d > day => return Ok((index, true)),
+            Some(_) => last = Some(index),
+            None => {}
+        }
+    }
+    let last = last
+        .ok_or("no existing day to put the new one next to, add the first day of a year by hand")?;
+    Ok((last, false))
+}
+
+fn insert(lines: &mut Vec<String>, index: usize, block: &[String]) {
+    lines.splice(index..index, block.iter().cloned());
+}
+
+/// Adds the module declaration and the `DAYS` entry to `src/years/y<year>/mod.rs`.
+fn register_module(source: &str, year: u16, day: u8) -> Result<String, String> {
+    let mut lines = source.lines().map(String::from).collect::<Vec<_>>();
+    let cfg = format!("#[cfg(feature = \"y{}-day{:02}\")]", year, day);
+
+    // Both the module declarations and the `DAYS` entries are preceded by their `#[cfg]`.
+    let declaration = |line: &str| day_of(line.strip_prefix("pub mod ")?);
+    let (index, before) = position(&lines, day, declaration)?;
+    let index = if before { index - 1 } else { index + 1 };
+    insert(
+        &mut lines,
+        index,
+        &[cfg.clone(), format!("pub mod day{:02};", day)],
+    );
+
+    let entry = |line: &str| day_of(line.strip_prefix('&')?);
+    let (index, before) = position(&lines, day, entry)?;
+    let index = if before { index - 1 } else { index + 1 };
+    insert(
+        &mut lines,
+        index,
+        &[
+            format!("    {}", cfg),
+            format!("    &day{:02}::Day{:02},", day, day),
+        ],
+    );
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Adds the `y<year>-dayNN` feature to `Cargo.toml`.
+fn register_feature(manifest: &str, year: u16, day: u8) -> Result<String, String> {
+    let mut lines = manifest.lines().map(String::from).collect::<Vec<_>>();
+    let prefix = format!("y{}-", year);
+    let feature = format!("{}day{:02}", prefix, day);
+
+    let all_days_entry = |line: &str| day_of(line.strip_prefix('"')?.strip_prefix(&prefix)?);
+    let (index, before) = position(&lines, day, all_days_entry)?;
+    let index = if before { index } else { index + 1 };
+    insert(&mut lines, index, &[format!("    \"{}\",", feature)]);
+
+    let definition =
+        |line: &str| day_of(line.strip_prefix(&prefix)?).filter(|_| line.contains(" = ["));
+    let (index, before) = position(&lines, day, definition)?;
+    let index = if before { index } else { index + 1 };
+    insert(&mut lines, index, &[format!("{} = []", feature)]);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+fn write_new(path: &Path, contents: &str) -> Result<(), String> {
+    if path.exists() {
+        return Err(format!("{} already exists", path.display()));
+    }
+    if let Some(parent) = path.parent() {
+        create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    fs::write(path, contents).map_err(|e| format!("couldn't write {}: {}", path.display(), e))?;
+    println!("created {}", path.display());
+    Ok(())
+}
+
+fn update(path: &Path, contents: &str) -> Result<(), String> {
+    fs::write(path, contents).map_err(|e| e.to_string())?;
+    println!("updated {}", path.display());
+    Ok(())
+}
+
+pub fn run(args: &[String]) -> Result<(), String> {
+    let [year, day] = args else {
+        return Err("expected a year and a day".to_string());
+    };
+    // Setting up a new year means registering it in `years::YEARS` first.
+    let year = year
+        .parse()
+        .ok()
+        .and_then(years::get)
+        .ok_or_else(|| format!("no solvers for year '{}'", year))?
+        .year;
+    let day: u8 = day
+        .parse()
+        .ok()
+        .filter(|d| (1..=25).contains(d))
+        .ok_or_else(|| format!("'{}' isn't a day between 1 and 25", day))?;
+    let nn = format!("{:02}", day);
+
+    let dir = format!("src/years/y{}", year);
+    let module = format!("{}/day{}.rs", dir, nn);
+    let registry = format!("{}/mod.rs", dir);
+    let modules = read_to_string(&registry).map_err(|e| e.to_string())?;
+    if modules.contains(&format!("pub mod day{};", nn)) || Path::new(&module).exists() {
+        return Err(format!("day {} of {} exists already", day, year));
+    }
+    // Both are worked out before writing anything, so a failure doesn't leave half a day behind.
+    let modules = register_module(&modules, year, day)?;
+    let manifest = read_to_string("Cargo.toml").map_err(|e| e.to_string())?;
+    let manifest = register_feature(&manifest, year, day)?;
+
+    write_new(
+        Path::new(&module),
+        &TEMPLATE
+            .replace("{{YEAR}}", &year.to_string())
+            .replace("{{NN}}", &nn)
+            .replace("{{N}}", &day.to_string()),
+    )?;
+    update(Path::new(&registry), &modules)?;
+    update(Path::new("Cargo.toml"), &manifest)?;
+
+    // Inputs and fixtures might have been saved before running `aoc new`.
+    let placeholders = [
+        format!("fixtures/{}/day{}/example.txt", year, nn),
+        format!("fixtures/{}/day{}/answers.txt", year, nn),
+        input::path(year, day).display().to_string(),
+    ];
+    for placeholder in placeholders {
+        if !Path::new(&placeholder).exists() {
+            write_new(Path::new(&placeholder), "")?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MODULES: &str = r#"use crate::solution::Solution;
+
+#[cfg(feature = "y2023-day01")]
+pub mod day01;
+#[cfg(feature = "y2023-day07")]
+pub mod day07;
+
+pub static DAYS: &[&dyn Solution] = &[
+    #[cfg(feature = "y2023-day01")]
+    &day01::Day01,
+    #[cfg(feature = "y2023-day07")]
+    &day07::Day07,
+];
+"#;
+
+    const MANIFEST: &str = r#"[package]
+name = "aoc"
+
+[features]
+all-days = [
+    "y2023-day01",
+    "y2023-day07",
+]
+y2023-day01 = []
+y2023-day07 = ["dep:itertools"]
+"#;
+
+    #[test]
+    fn test_register_module() {
+        let result = register_module(MODULES, 2023, 5).unwrap();
+        assert!(result.contains(
+            "pub mod day01;\n#[cfg(feature = \"y2023-day05\")]\npub mod day05;\n#[cfg(feature = \"y2023-day07\")]"
+        ));
+        assert!(result.contains(
+            "    &day01::Day01,\n    #[cfg(feature = \"y2023-day05\")]\n    &day05::Day05,\n    #[cfg(feature = \"y2023-day07\")]"
+        ));
+
+        let result = register_module(MODULES, 2023, 12).unwrap();
+        assert!(result
+            .contains("pub mod day07;\n#[cfg(feature = \"y2023-day12\")]\npub mod day12;\n\n"));
+        assert!(result.contains("    &day12::Day12,\n];"));
+    }
+
+    #[test]
+    fn test_register_feature() {
+        let result = register_feature(MANIFEST, 2023, 5).unwrap();
+        assert!(
+            result.contains("    \"y2023-day01\",\n    \"y2023-day05\",\n    \"y2023-day07\",\n")
+        );
+        assert!(result.contains("y2023-day01 = []\ny2023-day05 = []\ny2023-day07 = "));
+
+        let result = register_feature(MANIFEST, 2023, 25).unwrap();
+        assert!(result.contains("    \"y2023-day07\",\n    \"y2023-day25\",\n]"));
+        assert!(result.ends_with("y2023-day07 = [\"dep:itertools\"]\ny2023-day25 = []\n"));
+    }
+
+    #[test]
+    fn test_year_without_days() {
+        let modules =
+            "use crate::solution::Solution;\n\npub static DAYS: &[&dyn Solution] = &[];\n";
+        assert!(register_module(modules, 2024, 1).is_err());
+        assert!(register_feature(MANIFEST, 2024, 1).is_err());
+    }
+}
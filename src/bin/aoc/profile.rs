@@ -0,0 +1,52 @@
+use aoc::{input, profile, solution::Part, years};
+
+pub fn run(args: &[String]) -> Result<(), String> {
+    let Some((year, days)) = args.split_first() else {
+        return Err("expected a year".to_string());
+    };
+    let year = year
+        .parse()
+        .ok()
+        .and_then(years::get)
+        .ok_or_else(|| format!("no solvers for year '{}'", year))?;
+
+    let solutions = if days.is_empty() {
+        year.days.to_vec()
+    } else {
+        days.iter()
+            .map(|day| {
+                day.parse()
+                    .ok()
+                    .and_then(|day| year.get(day))
+                    .ok_or_else(|| format!("no solver for day '{}' of {}", day, year.year))
+            })
+            .collect::<Result<_, _>>()?
+    };
+
+    for solution in solutions {
+        // Like the report, a missing input only skips its day.
+        let input = match input::read(&input::path(year.year, solution.day())) {
+            Ok(input) => input,
+            Err(e) => {
+                println!("{} day {:02}: no input: {}", year.year, solution.day(), e);
+                continue;
+            }
+        };
+
+        for part in Part::ALL {
+            let profile = profile::run(solution, part, &input);
+            let Some(answer) = &profile.answer else {
+                continue;
+            };
+            println!(
+                "{} day {:02} part {}: {}",
+                year.year,
+                solution.day(),
+                part,
+                answer
+            );
+            print!("{}", profile);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use std::{fs, panic, path::Path, time::Duration};
+
+use aoc::{
+    answers::{self, Answers},
+    report,
+    runner::Limits,
+    years::{self, YEARS},
+};
+
+pub fn run(args: &[String]) -> Result<(), String> {
+    let mut selected = None;
+    let mut output = None;
+    let mut limits = Limits::default();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--output" => {
+                output = Some(args.next().ok_or("--output needs a value")?);
+            }
+            "--time-limit" => {
+                limits.time = args
+                    .next()
+                    .ok_or("--time-limit needs a value")?
+                    .parse()
+                    .ok()
+                    .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+                    .ok_or("--time-limit expects seconds")?;
+            }
+            _ if selected.is_none() => {
+                let year = arg
+                    .parse()
+                    .ok()
+                    .and_then(years::get)
+                    .ok_or_else(|| format!("no solvers for year '{}'", arg))?;
+                selected = Some(year);
+            }
+            _ => return Err(format!("unexpected argument '{}'", arg)),
+        }
+    }
+
+    // Failed parts show up in the report, the default hook would only clutter stderr.
+    panic::set_hook(Box::new(|_| {}));
+
+    let mut reports = vec![];
+    for year in YEARS
+        .iter()
+        .filter(|y| selected.is_none_or(|s| s.year == y.year))
+    {
+        let answers = Answers::load(&answers::path(year.year)).map_err(|e| e.to_string())?;
+        reports.push(report::generate(year, Path::new("."), &answers, &limits));
+    }
+    let report = reports.join("\n");
+
+    match output {
+        Some(path) => {
+            fs::write(path, report).map_err(|e| format!("couldn't write {}: {}", path, e))?;
+            println!("wrote {}", path);
+        }
+        None => print!("{}", report),
+    }
+    Ok(())
+}
@@ -0,0 +1,147 @@
+use std::{
+    panic, thread,
+    time::{Duration, Instant},
+};
+
+use aoc::{
+    explain::Trace,
+    input, parallel,
+    runner::Limits,
+    solution::{Part, Solution},
+    years,
+};
+
+pub fn run(args: &[String]) -> Result<(), String> {
+    let [year, day, options @ ..] = args else {
+        return Err("expected a year and a day".to_string());
+    };
+    let year = year
+        .parse()
+        .ok()
+        .and_then(years::get)
+        .ok_or_else(|| format!("no solvers for year '{}'", year))?;
+    if day == "all" {
+        return run_all(year, options);
+    }
+    let solution = day
+        .parse()
+        .ok()
+        .and_then(|day| year.get(day))
+        .ok_or_else(|| format!("no solver for day '{}' of {}", day, year.year))?;
+
+    let path = input::path(year.year, solution.day());
+    let input = input::read(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+
+    if !options.is_empty() {
+        return explain(year, solution, &input, options);
+    }
+    println!("Part 1: {}", solution.part_1(&input));
+    if let Some(answer) = solution.part_2(&input) {
+        println!("Part 2: {}", answer);
+    }
+    Ok(())
+}
+
+/// Prints the answers of a day together with how they were derived.
+fn explain(
+    year: &years::Year,
+    solution: &dyn Solution,
+    input: &str,
+    args: &[String],
+) -> Result<(), String> {
+    let mut json = false;
+    let mut explain = false;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--explain" => explain = true,
+            "--format" => {
+                json = match args.next().map(String::as_str) {
+                    Some("text") => false,
+                    Some("json") => true,
+                    _ => return Err("--format expects text or json".to_string()),
+                }
+            }
+            _ => return Err(format!("unexpected argument '{}'", arg)),
+        }
+    }
+    if !explain {
+        return Err("--format only applies to --explain".to_string());
+    }
+
+    let mut explained = false;
+    let mut trace = Trace::new(format!("Day {:02} of {}", solution.day(), year.year));
+    for part in Part::ALL {
+        let Some(answer) = solution.solve(part, input) else {
+            continue;
+        };
+        let mut step = Trace::new(format!("Part {}", part)).value(answer);
+        if let Some(explanation) = solution.explain(part, input) {
+            explained = true;
+            step = step.step(explanation);
+        }
+        trace = trace.step(step);
+    }
+    if !explained {
+        return Err(format!(
+            "day {} of {} has no explanation",
+            solution.day(),
+            year.year
+        ));
+    }
+
+    if json {
+        print!("{}", trace.json());
+    } else {
+        print!("{}", trace.text());
+    }
+    Ok(())
+}
+
+/// Solves every day of `year` concurrently and prints a table ordered by day.
+fn run_all(year: &years::Year, args: &[String]) -> Result<(), String> {
+    let mut limits = Limits::default();
+    let mut threads = thread::available_parallelism().map_or(1, |n| n.get());
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--time-limit" => {
+                limits.time = args
+                    .next()
+                    .ok_or("--time-limit needs a value")?
+                    .parse()
+                    .ok()
+                    .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+                    .ok_or("--time-limit expects seconds")?;
+            }
+            "--threads" => {
+                threads = args
+                    .next()
+                    .ok_or("--threads needs a value")?
+                    .parse()
+                    .ok()
+                    .filter(|n| *n > 0)
+                    .ok_or("--threads expects a positive number")?;
+            }
+            _ => return Err(format!("unexpected argument '{}'", arg)),
+        }
+    }
+
+    // Panics show up in the table, the default hook would only clutter stderr.
+    panic::set_hook(Box::new(|_| {}));
+
+    let start = Instant::now();
+    let input = |day| input::read(&input::path(year.year, day)).map_err(|e| e.to_string());
+    let runs = parallel::run_days(year.days, input, &limits, threads);
+
+    print!("{}", parallel::table(&runs));
+    println!(
+        "\n{} days in {:.2?} on {} thread{}",
+        runs.len(),
+        start.elapsed(),
+        threads,
+        if threads == 1 { "" } else { "s" }
+    );
+    Ok(())
+}
@@ -0,0 +1,206 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs::{self, read_dir},
+    io::{stdout, IsTerminal},
+    panic,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use aoc::{
+    answers::Answers,
+    input,
+    runner::{run_part, Limits, Outcome},
+    solution::{Part, Solution},
+    years::{self, Year},
+};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// The input, every file in the day's fixture directory and the solver's source file.
+fn watched_files(year: u16, day: u8) -> Vec<PathBuf> {
+    let mut files = vec![
+        input::path(year, day),
+        PathBuf::from(format!("src/years/y{}/day{:02}.rs", year, day)),
+    ];
+    if let Ok(entries) = read_dir(fixtures(year, day)) {
+        files.extend(entries.filter_map(|e| Some(e.ok()?.path())));
+    }
+    files
+}
+
+fn fixtures(year: u16, day: u8) -> PathBuf {
+    PathBuf::from(format!("fixtures/{}/day{:02}", year, day))
+}
+
+fn modification_times(files: &[PathBuf]) -> BTreeMap<PathBuf, SystemTime> {
+    files
+        .iter()
+        .filter_map(|f| Some((f.clone(), fs::metadata(f).ok()?.modified().ok()?)))
+        .collect()
+}
+
+fn changed_files<'a>(
+    before: &'a BTreeMap<PathBuf, SystemTime>,
+    after: &'a BTreeMap<PathBuf, SystemTime>,
+) -> Vec<&'a Path> {
+    let mut changed = after
+        .iter()
+        .filter(|(path, time)| before.get(*path) != Some(*time))
+        .map(|(path, _)| path.as_path())
+        .collect::<Vec<_>>();
+    changed.extend(
+        before
+            .keys()
+            .filter(|path| !after.contains_key(*path))
+            .map(PathBuf::as_path),
+    );
+    changed
+}
+
+/// The inputs to solve: the puzzle input and every `example*.txt` fixture, with the answers
+/// expected for them if known.
+fn cases(year: u16, day: u8) -> Vec<(String, PathBuf, Answers)> {
+    let mut cases = vec![(
+        "input".to_string(),
+        input::path(year, day),
+        Answers::load(&aoc::answers::path(year)).unwrap_or_default(),
+    )];
+
+    let dir = fixtures(year, day);
+    let mut examples = read_dir(&dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|e| e.ok()?.file_name().into_string().ok())
+        .filter(|name| name.starts_with("example") && name.ends_with(".txt"))
+        .collect::<Vec<_>>();
+    examples.sort();
+    for name in examples {
+        // `aoc examples` puts the answers of `example-2.txt` in `answers-2.txt` and so on.
+        let answers = dir.join(name.replacen("example", "answers", 1));
+        let answers = Answers::load(&answers).unwrap_or_default();
+        cases.push((name.clone(), dir.join(name), answers));
+    }
+    cases
+}
+
+fn highlight(text: &str) -> String {
+    if stdout().is_terminal() {
+        format!("\x1b[1;33m{}\x1b[0m", text)
+    } else {
+        text.to_string()
+    }
+}
+
+fn solve(
+    year: &Year,
+    solution: &'static dyn Solution,
+    previous: &mut HashMap<(String, Part), Outcome>,
+) {
+    for (name, path, answers) in cases(year.year, solution.day()) {
+        let input = match input::read(&path) {
+            Ok(input) if !input.trim().is_empty() => input,
+            Ok(_) => continue,
+            Err(e) => {
+                println!("{:<16} {}: {}", name, path.display(), e);
+                continue;
+            }
+        };
+
+        for part in Part::ALL {
+            let run = run_part(solution, part, input.clone().into(), &Limits::default());
+            if run.outcome == Outcome::Unsolved {
+                continue;
+            }
+
+            let mut line = format!("{:<16} part {}: {}", name, part, run.outcome);
+            if let Outcome::Solved(answer) = &run.outcome {
+                match answers.get(solution.day(), part) {
+                    Some(expected) if expected == answer => line += " (ok)",
+                    Some(expected) => line += &highlight(&format!(" (expected {})", expected)),
+                    None => {}
+                }
+            }
+            match previous.insert((name.clone(), part), run.outcome.clone()) {
+                Some(before) if before != run.outcome => {
+                    line = highlight(&format!("{} (was {})", line, before))
+                }
+                _ => {}
+            }
+            println!("{}", line);
+        }
+    }
+}
+
+pub fn run(args: &[String]) -> Result<(), String> {
+    let (year, day) = match args {
+        [day] => (years::latest(), day),
+        [year, day] => (
+            year.parse()
+                .ok()
+                .and_then(years::get)
+                .ok_or_else(|| format!("no solvers for year '{}'", year))?,
+            day,
+        ),
+        _ => return Err("expected an optional year and a day".to_string()),
+    };
+    let solution = day
+        .parse()
+        .ok()
+        .and_then(|day| year.get(day))
+        .ok_or_else(|| format!("no solver for day '{}' of {}", day, year.year))?;
+
+    // Panics are shown as the outcome of the part.
+    panic::set_hook(Box::new(|_| {}));
+
+    println!(
+        "watching day {:02} of {}, press Ctrl-C to stop",
+        solution.day(),
+        year.year
+    );
+    let mut previous = HashMap::new();
+    let mut times = modification_times(&watched_files(year.year, solution.day()));
+    solve(year, solution, &mut previous);
+
+    loop {
+        thread::sleep(POLL_INTERVAL);
+        let now = modification_times(&watched_files(year.year, solution.day()));
+        let changed = changed_files(&times, &now);
+        if changed.is_empty() {
+            continue;
+        }
+
+        println!();
+        for path in changed {
+            if path.extension().is_some_and(|e| e == "rs") {
+                println!(
+                    "{} changed, rebuild and restart `aoc watch` to pick it up",
+                    path.display()
+                );
+            } else {
+                println!("{} changed", path.display());
+            }
+        }
+        solve(year, solution, &mut previous);
+        times = now;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_changed_files() {
+        let t = SystemTime::UNIX_EPOCH;
+        let later = t + Duration::from_secs(1);
+        let before = BTreeMap::from([("a".into(), t), ("b".into(), t), ("c".into(), t)]);
+        let after = BTreeMap::from([("a".into(), t), ("b".into(), later), ("d".into(), t)]);
+
+        assert_eq!(
+            changed_files(&before, &after),
+            vec![Path::new("b"), Path::new("d"), Path::new("c")]
+        );
+    }
+}
@@ -0,0 +1,385 @@
+//! Compact boolean grids backed by `u64` words, for puzzles working on maps of set and unset
+//! cells. Every row starts at a word boundary and unused bits are kept zero, so rows, columns and
+//! whole grids can be compared, hashed and combined word by word.
+
+use std::ops::{BitAnd, BitOr, BitXor, Not};
+
+const BITS: usize = u64::BITS as usize;
+
+fn words_for(len: usize) -> usize {
+    len.div_ceil(BITS)
+}
+
+/// Clears the bits past `len` in the last word of `words`.
+fn mask_tail(words: &mut [u64], len: usize) {
+    if let (Some(last), tail @ 1..) = (words.last_mut(), len % BITS) {
+        *last &= (1 << tail) - 1;
+    }
+}
+
+/// Writes `src` moved by `by` bits towards higher indices into `dst`, bits moved past either end
+/// are dropped.
+fn shift_words(src: &[u64], dst: &mut [u64], by: isize, len: usize) {
+    let (word_shift, bit_shift) = (by.unsigned_abs() / BITS, by.unsigned_abs() % BITS);
+    let word = |i: Option<usize>| i.and_then(|i| src.get(i)).copied().unwrap_or(0);
+
+    for (i, d) in dst.iter_mut().enumerate() {
+        *d = if by >= 0 {
+            let near = word(i.checked_sub(word_shift));
+            let far = word(i.checked_sub(word_shift + 1));
+            match bit_shift {
+                0 => near,
+                _ => (near << bit_shift) | (far >> (BITS - bit_shift)),
+            }
+        } else {
+            let near = word(Some(i + word_shift));
+            let far = word(Some(i + word_shift + 1));
+            match bit_shift {
+                0 => near,
+                _ => (near >> bit_shift) | (far << (BITS - bit_shift)),
+            }
+        };
+    }
+    mask_tail(dst, len);
+}
+
+fn ones(words: &[u64]) -> impl Iterator<Item = usize> + '_ {
+    words.iter().enumerate().flat_map(|(i, &word)| {
+        let mut word = word;
+        std::iter::from_fn(move || {
+            let bit = (word != 0).then(|| word.trailing_zeros() as usize)?;
+            word &= word - 1;
+            Some(i * BITS + bit)
+        })
+    })
+}
+
+/// A fixed number of bits, e.g. a row or column of a [`BitGrid`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitSet {
+    len: usize,
+    words: Vec<u64>,
+}
+
+impl BitSet {
+    pub fn new(len: usize) -> Self {
+        Self {
+            len,
+            words: vec![0; words_for(len)],
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn get(&self, index: usize) -> bool {
+        assert!(index < self.len, "bit {} out of {}", index, self.len);
+        self.words[index / BITS] & (1 << (index % BITS)) != 0
+    }
+
+    pub fn set(&mut self, index: usize, value: bool) {
+        assert!(index < self.len, "bit {} out of {}", index, self.len);
+        let mask = 1 << (index % BITS);
+        if value {
+            self.words[index / BITS] |= mask;
+        } else {
+            self.words[index / BITS] &= !mask;
+        }
+    }
+
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// The indices of the set bits in ascending order.
+    pub fn iter_ones(&self) -> impl Iterator<Item = usize> + '_ {
+        ones(&self.words)
+    }
+
+    /// Moves every bit `by` positions towards higher indices (towards lower ones if negative).
+    pub fn shifted(&self, by: isize) -> Self {
+        let mut result = Self::new(self.len);
+        shift_words(&self.words, &mut result.words, by, self.len);
+        result
+    }
+
+    fn zip_with(&self, other: &Self, f: impl Fn(u64, u64) -> u64) -> Self {
+        assert_eq!(self.len, other.len, "bit sets of different lengths");
+        Self {
+            len: self.len,
+            words: self
+                .words
+                .iter()
+                .zip(&other.words)
+                .map(|(a, b)| f(*a, *b))
+                .collect(),
+        }
+    }
+}
+
+/// A `width` × `height` grid of bits, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitGrid {
+    width: usize,
+    height: usize,
+    /// Words per row.
+    stride: usize,
+    words: Vec<u64>,
+}
+
+impl BitGrid {
+    pub fn new(width: usize, height: usize) -> Self {
+        let stride = words_for(width);
+        Self {
+            width,
+            height,
+            stride,
+            words: vec![0; stride * height],
+        }
+    }
+
+    /// Parses a grid with one line per row, setting the cells for which `is_set` holds.
+    pub fn parse(input: &str, is_set: impl Fn(char) -> bool) -> Result<Self, String> {
+        let lines = input.lines().collect::<Vec<_>>();
+        let width = lines.first().map_or(0, |l| l.chars().count());
+
+        let mut grid = Self::new(width, lines.len());
+        for (y, line) in lines.iter().enumerate() {
+            if line.chars().count() != width {
+                return Err(format!("line {} isn't {} cells wide", y + 1, width));
+            }
+            for (x, c) in line.chars().enumerate() {
+                grid.set(x, y, is_set(c));
+            }
+        }
+        Ok(grid)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn row_words(&self, y: usize) -> &[u64] {
+        &self.words[y * self.stride..(y + 1) * self.stride]
+    }
+
+    fn index(&self, x: usize, y: usize) -> (usize, u64) {
+        assert!(
+            x < self.width && y < self.height,
+            "({}, {}) out of the {}x{} grid",
+            x,
+            y,
+            self.width,
+            self.height
+        );
+        (y * self.stride + x / BITS, 1 << (x % BITS))
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> bool {
+        let (word, mask) = self.index(x, y);
+        self.words[word] & mask != 0
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, value: bool) {
+        let (word, mask) = self.index(x, y);
+        if value {
+            self.words[word] |= mask;
+        } else {
+            self.words[word] &= !mask;
+        }
+    }
+
+    pub fn row(&self, y: usize) -> BitSet {
+        BitSet {
+            len: self.width,
+            words: self.row_words(y).to_vec(),
+        }
+    }
+
+    pub fn column(&self, x: usize) -> BitSet {
+        let mut column = BitSet::new(self.height);
+        for y in 0..self.height {
+            column.set(y, self.get(x, y));
+        }
+        column
+    }
+
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// The coordinates of the set cells, row by row.
+    pub fn iter_ones(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (0..self.height).flat_map(move |y| ones(self.row_words(y)).map(move |x| (x, y)))
+    }
+
+    pub fn transposed(&self) -> Self {
+        let mut result = Self::new(self.height, self.width);
+        for (x, y) in self.iter_ones() {
+            result.set(y, x, true);
+        }
+        result
+    }
+
+    /// Moves every cell by `dx` columns and `dy` rows, cells moved past the border are dropped.
+    pub fn shifted(&self, dx: isize, dy: isize) -> Self {
+        let mut result = Self::new(self.width, self.height);
+        for y in 0..self.height {
+            let Some(source) = y.checked_add_signed(-dy).filter(|s| *s < self.height) else {
+                continue;
+            };
+            let row = y * self.stride..(y + 1) * self.stride;
+            shift_words(
+                self.row_words(source),
+                &mut result.words[row],
+                dx,
+                self.width,
+            );
+        }
+        result
+    }
+
+    fn zip_with(&self, other: &Self, f: impl Fn(u64, u64) -> u64) -> Self {
+        assert_eq!(
+            (self.width, self.height),
+            (other.width, other.height),
+            "grids of different sizes"
+        );
+        Self {
+            words: self
+                .words
+                .iter()
+                .zip(&other.words)
+                .map(|(a, b)| f(*a, *b))
+                .collect(),
+            ..*self
+        }
+    }
+}
+
+macro_rules! impl_bit_ops {
+    ($type:ty) => {
+        impl BitAnd for &$type {
+            type Output = $type;
+
+            fn bitand(self, other: Self) -> $type {
+                self.zip_with(other, |a, b| a & b)
+            }
+        }
+
+        impl BitOr for &$type {
+            type Output = $type;
+
+            fn bitor(self, other: Self) -> $type {
+                self.zip_with(other, |a, b| a | b)
+            }
+        }
+
+        impl BitXor for &$type {
+            type Output = $type;
+
+            fn bitxor(self, other: Self) -> $type {
+                self.zip_with(other, |a, b| a ^ b)
+            }
+        }
+    };
+}
+
+impl_bit_ops!(BitSet);
+impl_bit_ops!(BitGrid);
+
+impl Not for &BitSet {
+    type Output = BitSet;
+
+    fn not(self) -> BitSet {
+        let mut result = BitSet {
+            len: self.len,
+            words: self.words.iter().map(|w| !w).collect(),
+        };
+        mask_tail(&mut result.words, self.len);
+        result
+    }
+}
+
+impl Not for &BitGrid {
+    type Output = BitGrid;
+
+    fn not(self) -> BitGrid {
+        let mut result = BitGrid {
+            words: self.words.iter().map(|w| !w).collect(),
+            ..*self
+        };
+        for row in result.words.chunks_mut(self.stride.max(1)) {
+            mask_tail(row, self.width);
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GRID: &str = "#.#\n.##\n...\n";
+
+    #[test]
+    fn test_rows_columns_and_transposition() {
+        let grid = BitGrid::parse(GRID, |c| c == '#').unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 3));
+        assert_eq!(grid.count_ones(), 4);
+        assert_eq!(grid.row(1).iter_ones().collect::<Vec<_>>(), vec![1, 2]);
+        assert_eq!(grid.column(2).iter_ones().collect::<Vec<_>>(), vec![0, 1]);
+        assert_eq!(grid.transposed().row(2), grid.column(2));
+        assert_eq!(grid.transposed().transposed(), grid);
+
+        assert!(BitGrid::parse("##\n#\n", |c| c == '#').is_err());
+    }
+
+    #[test]
+    fn test_shifts_drop_cells_at_the_border() {
+        let grid = BitGrid::parse(GRID, |c| c == '#').unwrap();
+        let moved = grid.shifted(1, 1);
+        assert_eq!(moved.iter_ones().collect::<Vec<_>>(), vec![(1, 1), (2, 2)]);
+        assert_eq!(
+            grid.shifted(-2, 0).iter_ones().collect::<Vec<_>>(),
+            vec![(0, 0), (0, 1)]
+        );
+
+        // Shifts across word boundaries.
+        let mut wide = BitGrid::new(130, 1);
+        wide.set(63, 0, true);
+        wide.set(127, 0, true);
+        assert_eq!(
+            wide.shifted(2, 0).iter_ones().collect::<Vec<_>>(),
+            vec![(65, 0), (129, 0)]
+        );
+        assert_eq!(
+            wide.shifted(-64, 0).iter_ones().collect::<Vec<_>>(),
+            vec![(63, 0)]
+        );
+        assert_eq!(wide.shifted(3, 0).count_ones(), 1);
+    }
+
+    #[test]
+    fn test_boolean_ops_keep_padding_clear() {
+        let grid = BitGrid::parse(GRID, |c| c == '#').unwrap();
+        let inverted = !&grid;
+        assert_eq!(inverted.count_ones(), 5);
+        assert_eq!((&grid | &inverted).count_ones(), 9);
+        assert_eq!((&grid & &inverted).count_ones(), 0);
+        assert_eq!((&grid ^ &grid.shifted(1, 0)).row(0).count_ones(), 3);
+
+        let row = grid.row(0);
+        assert_eq!((!&row).iter_ones().collect::<Vec<_>>(), vec![1]);
+        assert_eq!(row.shifted(1).iter_ones().collect::<Vec<_>>(), vec![1]);
+    }
+}
@@ -0,0 +1,95 @@
+//! Cooperative cancellation for solvers running on a worker thread.
+//!
+//! The runner installs a [`CancellationToken`] on the worker thread and long running loops call
+//! [`checkpoint`] once per iteration. Solvers aren't aware of the token otherwise, so a cancelled
+//! solver is stopped by unwinding out of the checkpoint with a [`Cancelled`] payload.
+//!
+//! Going over the memory limit stops a solver anywhere instead, see [`stop_at_memory_limit`].
+
+use std::{
+    cell::RefCell,
+    panic,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    thread,
+    time::Duration,
+};
+
+#[derive(Debug, Default)]
+struct Flags {
+    cancelled: AtomicBool,
+    memory_limit_exceeded: AtomicBool,
+}
+
+/// Shared between the runner and its worker thread: the runner cancels the solver through it and
+/// the worker reports back through it when it got stopped at its memory limit.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<Flags>);
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.cancelled.load(Ordering::Relaxed)
+    }
+
+    /// Whether the solver tried to allocate more than its memory limit and got stopped.
+    pub fn memory_limit_exceeded(&self) -> bool {
+        self.0.memory_limit_exceeded.load(Ordering::Relaxed)
+    }
+}
+
+/// Panic payload used to unwind out of a cancelled solver.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cancelled;
+
+thread_local! {
+    static CURRENT: RefCell<Option<CancellationToken>> = const { RefCell::new(None) };
+}
+
+/// Installs `token` for the current thread, [`checkpoint`] will stop once it's cancelled.
+pub fn set_current(token: CancellationToken) {
+    CURRENT.with(|current| *current.borrow_mut() = Some(token));
+}
+
+/// Stops the current solver if its token got cancelled. Does nothing on threads without a token,
+/// e.g. in the per-day binaries or in tests.
+pub fn checkpoint() {
+    let cancelled = CURRENT.with(|current| {
+        current
+            .borrow()
+            .as_ref()
+            .is_some_and(CancellationToken::is_cancelled)
+    });
+
+    if cancelled {
+        panic::resume_unwind(Box::new(Cancelled));
+    }
+}
+
+/// Called by [`crate::alloc::CountingAllocator`] instead of allocating past the thread's memory
+/// limit. Allocators must not unwind and failing the allocation aborts the process, so the
+/// thread reports the exceeded limit through its token and never returns. The runner abandons it
+/// like a solver that doesn't stop at its deadline.
+///
+/// Runs inside the allocator, so it must not allocate itself.
+pub(crate) fn stop_at_memory_limit() -> ! {
+    let _ = CURRENT.try_with(|current| {
+        if let Ok(current) = current.try_borrow() {
+            if let Some(token) = current.as_ref() {
+                token.0.memory_limit_exceeded.store(true, Ordering::Relaxed);
+            }
+        }
+    });
+    loop {
+        thread::sleep(Duration::from_secs(3600));
+    }
+}
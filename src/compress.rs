@@ -0,0 +1,240 @@
+//! Coordinate compression for geometry on huge but sparse coordinates.
+//!
+//! An [`Axis`] splits the integer line at a sorted set of breakpoints into cells: cell `i` covers
+//! the original coordinates `start(i)..start(i + 1)`. Breaking at `a` and `b + 1` makes `a..=b`
+//! map to whole cells, so everything that's uniform between breakpoints is handled once per cell
+//! and weighted with the cell's size. [`CompressedGrid`] does that in two dimensions and answers
+//! flood fill, area and distance queries in original units.
+
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, VecDeque},
+    ops::RangeInclusive,
+};
+
+use crate::bitgrid::BitGrid;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Axis {
+    /// Sorted and distinct, the last bound only ends the last cell.
+    bounds: Vec<i64>,
+}
+
+impl Axis {
+    pub fn new(breakpoints: impl IntoIterator<Item = i64>) -> Self {
+        let mut bounds = breakpoints.into_iter().collect::<Vec<_>>();
+        bounds.sort_unstable();
+        bounds.dedup();
+        assert!(bounds.len() >= 2, "an axis needs at least two breakpoints");
+        Self { bounds }
+    }
+
+    /// An axis starting at 0 with consecutive cells of the given widths, e.g. to give some rows
+    /// of a grid a larger weight.
+    pub fn from_widths(widths: impl IntoIterator<Item = i64>) -> Self {
+        let mut bounds = vec![0];
+        for width in widths {
+            assert!(width > 0, "cells must be at least 1 wide");
+            bounds.push(bounds[bounds.len() - 1] + width);
+        }
+        Self::new(bounds)
+    }
+
+    /// The number of cells.
+    pub fn len(&self) -> usize {
+        self.bounds.len() - 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The first original coordinate covered by `cell`.
+    pub fn start(&self, cell: usize) -> i64 {
+        self.bounds[cell]
+    }
+
+    pub fn width(&self, cell: usize) -> i64 {
+        self.bounds[cell + 1] - self.bounds[cell]
+    }
+
+    /// The cell covering `coordinate`, if any.
+    pub fn cell(&self, coordinate: i64) -> Option<usize> {
+        let index = self.bounds.partition_point(|b| *b <= coordinate);
+        (1..self.bounds.len()).contains(&index).then(|| index - 1)
+    }
+
+    /// The cells covering `from..=to`. Both ends have to be covered by the axis, for an exact
+    /// match `from` and `to + 1` have to be breakpoints.
+    pub fn cells(&self, from: i64, to: i64) -> RangeInclusive<usize> {
+        let first = self.cell(from).expect("range starts outside of the axis");
+        let last = self.cell(to).expect("range ends outside of the axis");
+        first..=last
+    }
+}
+
+/// A grid of compressed cells, some of which are blocked.
+#[derive(Debug, Clone)]
+pub struct CompressedGrid {
+    pub xs: Axis,
+    pub ys: Axis,
+    blocked: BitGrid,
+}
+
+impl CompressedGrid {
+    pub fn new(xs: Axis, ys: Axis) -> Self {
+        let blocked = BitGrid::new(xs.len(), ys.len());
+        Self { xs, ys, blocked }
+    }
+
+    /// Blocks the cells covering the rectangle between the original coordinates `from` and `to`,
+    /// both inclusive.
+    pub fn block(&mut self, from: (i64, i64), to: (i64, i64)) {
+        let xs = self.xs.cells(from.0.min(to.0), from.0.max(to.0));
+        let ys = self.ys.cells(from.1.min(to.1), from.1.max(to.1));
+        for y in ys {
+            for x in xs.clone() {
+                self.blocked.set(x, y, true);
+            }
+        }
+    }
+
+    pub fn is_blocked(&self, (x, y): (usize, usize)) -> bool {
+        self.blocked.get(x, y)
+    }
+
+    /// The number of original cells represented by the compressed cell.
+    pub fn weight(&self, (x, y): (usize, usize)) -> i64 {
+        self.xs.width(x) * self.ys.width(y)
+    }
+
+    fn neighbours(&self, (x, y): (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        [(1, 0), (-1, 0), (0, 1), (0, -1)]
+            .into_iter()
+            .filter_map(move |(dx, dy)| {
+                Some((x.checked_add_signed(dx)?, y.checked_add_signed(dy)?))
+            })
+            .filter(|(x, y)| *x < self.xs.len() && *y < self.ys.len())
+            .filter(|cell| !self.is_blocked(*cell))
+    }
+
+    /// The open cells reachable from `start` without crossing blocked cells.
+    pub fn flood_fill(&self, start: (usize, usize)) -> BitGrid {
+        let mut reached = BitGrid::new(self.xs.len(), self.ys.len());
+        if self.is_blocked(start) {
+            return reached;
+        }
+
+        reached.set(start.0, start.1, true);
+        let mut queue = VecDeque::from([start]);
+        while let Some(cell) = queue.pop_front() {
+            for (x, y) in self.neighbours(cell) {
+                if !reached.get(x, y) {
+                    reached.set(x, y, true);
+                    queue.push_back((x, y));
+                }
+            }
+        }
+        reached
+    }
+
+    /// The total area in original units of the given compressed cells.
+    pub fn area(&self, cells: &BitGrid) -> i64 {
+        cells.iter_ones().map(|cell| self.weight(cell)).sum()
+    }
+
+    /// The area in original units of the whole grid.
+    pub fn total_area(&self) -> i64 {
+        let extent = |axis: &Axis| axis.start(axis.len()) - axis.start(0);
+        extent(&self.xs) * extent(&self.ys)
+    }
+
+    /// The shortest distances in original units from `start` to every open cell, moving between
+    /// neighbouring open cells. Cells are represented by their first coordinates, so a step costs
+    /// the width of the lower of the two cells: the one left behind when moving right or down, the
+    /// one entered when moving left or up.
+    pub fn distances(&self, start: (usize, usize)) -> Vec<Vec<Option<i64>>> {
+        let mut distances = vec![vec![None; self.xs.len()]; self.ys.len()];
+        let mut queue = BinaryHeap::from([Reverse((0, start))]);
+
+        while let Some(Reverse((distance, cell))) = queue.pop() {
+            if distances[cell.1][cell.0].is_some() {
+                continue;
+            }
+            distances[cell.1][cell.0] = Some(distance);
+
+            for next in self.neighbours(cell) {
+                if distances[next.1][next.0].is_none() {
+                    let step = (self.xs.start(next.0) - self.xs.start(cell.0)).abs()
+                        + (self.ys.start(next.1) - self.ys.start(cell.1)).abs();
+                    queue.push(Reverse((distance + step, next)));
+                }
+            }
+        }
+        distances
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_axis() {
+        let axis = Axis::new([10, 0, 1_000_000, 10]);
+        assert_eq!(axis.len(), 2);
+        assert_eq!(axis.cell(-1), None);
+        assert_eq!(axis.cell(0), Some(0));
+        assert_eq!(axis.cell(999_999), Some(1));
+        assert_eq!(axis.cell(1_000_000), None);
+        assert_eq!(axis.width(1), 999_990);
+        assert_eq!(axis.cells(10, 999_999), 1..=1);
+
+        let axis = Axis::from_widths([1, 5, 1]);
+        assert_eq!((axis.start(2), axis.width(1)), (6, 5));
+    }
+
+    #[test]
+    fn test_flood_fill_and_area() {
+        // A 1000 × 1000 square with a wall around the inner 998 × 998, with 1 unit of margin.
+        let corners = [0, 1, 999, 1000];
+        let axis = || Axis::new(corners.iter().copied().chain([-1, 1001]));
+        let mut grid = CompressedGrid::new(axis(), axis());
+        for (from, to) in [
+            ((0, 0), (999, 0)),
+            ((0, 999), (999, 999)),
+            ((0, 0), (0, 999)),
+            ((999, 0), (999, 999)),
+        ] {
+            grid.block(from, to);
+        }
+
+        let outside = grid.flood_fill((0, 0));
+        assert_eq!(grid.area(&outside), 1002 * 1002 - 1000 * 1000);
+        assert_eq!(grid.total_area() - grid.area(&outside), 1000 * 1000);
+        let inside = grid.flood_fill((2, 2));
+        assert_eq!(grid.area(&inside), 998 * 998);
+    }
+
+    #[test]
+    fn test_distances_go_around_walls() {
+        let mut grid = CompressedGrid::new(Axis::new([0, 1, 2, 100]), Axis::new([0, 50, 51, 60]));
+        // Blocks the middle column except for the last row.
+        grid.block((1, 0), (1, 50));
+
+        let distances = grid.distances((0, 0));
+        assert_eq!(distances[0][2], Some(2 + 51 + 51));
+        assert_eq!(distances[2][2], Some(2 + 51));
+        assert_eq!(distances[0][1], None);
+    }
+
+    #[test]
+    fn test_distances_in_both_directions() {
+        let grid = CompressedGrid::new(Axis::from_widths([1, 5, 1]), Axis::from_widths([1]));
+
+        // Moving right costs the width of the cell left behind.
+        assert_eq!(grid.distances((0, 0))[0], [Some(0), Some(1), Some(6)]);
+        // Moving left costs the width of the cell entered.
+        assert_eq!(grid.distances((2, 0))[0], [Some(6), Some(5), Some(0)]);
+    }
+}
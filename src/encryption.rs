@@ -0,0 +1,126 @@
+//! Encryption of puzzle inputs at rest, so they can be committed without publishing them.
+//!
+//! Inputs are encrypted in place with ChaCha20-Poly1305. An encrypted file consists of
+//! [`ENCRYPTED_MAGIC`], the 12 byte nonce and the ciphertext including the authentication tag.
+//!
+//! The 32 byte key is given hex encoded, either in the `AOC_INPUT_KEY` environment variable or in
+//! a key file. The key file defaults to `.aoc-key` and can be changed with `AOC_INPUT_KEY_FILE`.
+
+use std::{env, fs, io, path::PathBuf};
+
+use chacha20poly1305::{
+    aead::{Aead, AeadCore, KeyInit, OsRng},
+    ChaCha20Poly1305, Nonce,
+};
+
+use crate::input::{invalid_data, is_encrypted, ENCRYPTED_MAGIC};
+
+const NONCE_LEN: usize = 12;
+
+pub const KEY_VAR: &str = "AOC_INPUT_KEY";
+pub const KEY_FILE_VAR: &str = "AOC_INPUT_KEY_FILE";
+pub const DEFAULT_KEY_FILE: &str = ".aoc-key";
+
+#[derive(Clone)]
+pub struct Key([u8; 32]);
+
+impl Key {
+    pub fn generate() -> Self {
+        Self(ChaCha20Poly1305::generate_key(&mut OsRng).into())
+    }
+
+    pub fn from_hex(s: &str) -> Result<Self, String> {
+        let bytes = hex::decode(s.trim()).map_err(|e| format!("key isn't valid hex: {}", e))?;
+        let bytes: [u8; 32] = bytes
+            .try_into()
+            .map_err(|b: Vec<u8>| format!("key must be 32 bytes, got {}", b.len()))?;
+        Ok(Self(bytes))
+    }
+
+    pub fn to_hex(&self) -> String {
+        hex::encode(self.0)
+    }
+
+    pub fn key_file() -> PathBuf {
+        env::var_os(KEY_FILE_VAR)
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from(DEFAULT_KEY_FILE))
+    }
+
+    /// Loads the key from the environment or the key file, `None` if neither is configured.
+    pub fn load() -> io::Result<Option<Self>> {
+        let hex = match env::var(KEY_VAR) {
+            Ok(hex) => hex,
+            Err(_) => match fs::read_to_string(Self::key_file()) {
+                Ok(hex) => hex,
+                Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+                Err(e) => return Err(e),
+            },
+        };
+
+        Self::from_hex(&hex).map(Some).map_err(invalid_data)
+    }
+
+    fn cipher(&self) -> ChaCha20Poly1305 {
+        ChaCha20Poly1305::new(&self.0.into())
+    }
+}
+
+pub fn encrypt(key: &Key, plaintext: &[u8]) -> Vec<u8> {
+    let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let ciphertext = key
+        .cipher()
+        .encrypt(&nonce, plaintext)
+        .expect("encryption doesn't fail for in-memory buffers");
+
+    [ENCRYPTED_MAGIC, nonce.as_slice(), &ciphertext].concat()
+}
+
+pub fn decrypt(key: &Key, data: &[u8]) -> Result<Vec<u8>, String> {
+    if !is_encrypted(data) {
+        return Err("not an encrypted input".to_string());
+    }
+    let data = &data[ENCRYPTED_MAGIC.len()..];
+    if data.len() < NONCE_LEN {
+        return Err("encrypted input is truncated".to_string());
+    }
+    let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+
+    key.cipher()
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| "decryption failed, wrong key or corrupted file".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roundtrip() {
+        let key = Key::generate();
+        let encrypted = encrypt(&key, b"1abc2\npqr3stu8vwx\n");
+
+        assert!(is_encrypted(&encrypted));
+        assert_eq!(decrypt(&key, &encrypted).unwrap(), b"1abc2\npqr3stu8vwx\n");
+    }
+
+    #[test]
+    fn test_wrong_key_or_tampering_is_detected() {
+        let key = Key::generate();
+        let mut encrypted = encrypt(&key, b"1abc2\n");
+
+        assert!(decrypt(&Key::generate(), &encrypted).is_err());
+
+        let last = encrypted.len() - 1;
+        encrypted[last] ^= 1;
+        assert!(decrypt(&key, &encrypted).is_err());
+    }
+
+    #[test]
+    fn test_key_from_hex() {
+        let key = Key::generate();
+        assert_eq!(Key::from_hex(&key.to_hex()).unwrap().0, key.0);
+        assert!(Key::from_hex("abcd").is_err());
+        assert!(Key::from_hex("not hex").is_err());
+    }
+}
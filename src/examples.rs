@@ -0,0 +1,216 @@
+//! Extracts the examples from a puzzle description saved as HTML or Markdown, so fixtures don't
+//! have to be copied by hand. Works on local files only, nothing is fetched.
+//!
+//! Examples are the `<pre><code>` blocks (fenced code blocks in Markdown). The answer of a part is
+//! the last emphasised code in its description, e.g. `<code><em>142</em></code>` or ``*`142`*``,
+//! and belongs to the last example before it.
+
+use crate::solution::Part;
+
+const PART_TWO: &str = "--- Part Two ---";
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Examples {
+    /// All distinct example blocks in the order they appear.
+    pub blocks: Vec<String>,
+    /// The answers with the index of the block they are for. A part without a block of its own
+    /// reuses one of the part before.
+    pub answers: Vec<(Part, usize, String)>,
+}
+
+struct Format {
+    block: (&'static str, &'static str),
+    answers: &'static [(&'static str, &'static str)],
+}
+
+const HTML: Format = Format {
+    block: ("<pre><code>", "</code></pre>"),
+    answers: &[
+        ("<code><em>", "</em></code>"),
+        ("<em><code>", "</code></em>"),
+    ],
+};
+
+const MARKDOWN: Format = Format {
+    block: ("```", "```"),
+    answers: &[("`*", "*`"), ("*`", "`*")],
+};
+
+/// Yields the start offset and the text between every non-overlapping `open`...`close` pair.
+fn delimited<'a>(
+    s: &'a str,
+    (open, close): (&'a str, &'a str),
+) -> impl Iterator<Item = (usize, &'a str)> + 'a {
+    let mut offset = 0;
+    std::iter::from_fn(move || {
+        let start = offset + s[offset..].find(open)?;
+        let content = start + open.len();
+        let end = content + s[content..].find(close)?;
+        offset = end + close.len();
+        Some((start, &s[content..end]))
+    })
+}
+
+/// Removes tags like the `<em>` highlighting parts of an example and decodes HTML entities.
+fn to_text(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&#x27;", "'")
+        .replace("&amp;", "&")
+}
+
+pub fn extract(page: &str) -> Examples {
+    let (format, is_html) = if page.contains(HTML.block.0) {
+        (&HTML, true)
+    } else {
+        (&MARKDOWN, false)
+    };
+    let decode = |s: &str| if is_html { to_text(s) } else { s.to_string() };
+
+    let mut examples = Examples::default();
+    let (part_1, part_2) = match page.split_once(PART_TWO) {
+        Some((part_1, part_2)) => (part_1, Some(part_2)),
+        None => (page, None),
+    };
+    let parts = [(Part::One, Some(part_1)), (Part::Two, part_2)];
+
+    // The index of the latest block, in case a part doesn't have one of its own.
+    let mut latest = 0;
+    for (part, section) in parts {
+        let Some(section) = section else {
+            continue;
+        };
+
+        // Where each block of the section starts and which of the distinct blocks it is.
+        let mut starts = vec![];
+        for (start, block) in delimited(section, format.block) {
+            // Fenced blocks start with an optional language tag on the opening line.
+            let block = if is_html {
+                block
+            } else {
+                block.split_once('\n').map_or("", |(_, code)| code)
+            };
+            let block = decode(block);
+            if block.trim().is_empty() {
+                continue;
+            }
+            let index = match examples.blocks.iter().position(|b| *b == block) {
+                Some(index) => index,
+                None => {
+                    examples.blocks.push(block);
+                    examples.blocks.len() - 1
+                }
+            };
+            starts.push((start, index));
+        }
+
+        // Blanked out instead of removed, so offsets in the prose are offsets in the section.
+        let mut prose = section.to_string();
+        for (_, block) in delimited(section, format.block) {
+            prose = prose.replace(block, &" ".repeat(block.len()));
+        }
+
+        let answer = format
+            .answers
+            .iter()
+            .flat_map(|&delimiters| delimited(&prose, delimiters))
+            .max_by_key(|(start, _)| *start);
+        if let Some((start, answer)) = answer {
+            let block = starts
+                .iter()
+                .rev()
+                .find(|(block_start, _)| *block_start < start)
+                .map_or(latest, |(_, index)| *index);
+            examples
+                .answers
+                .push((part, block, decode(answer).trim().to_string()));
+        }
+        latest = starts.last().map_or(latest, |(_, index)| *index);
+    }
+
+    examples
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HTML_PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 1: Trebuchet?! ---</h2>
+<p>For example:</p>
+<pre><code>1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet
+</code></pre>
+<p>In this example, the calibration values of these four lines are <code>12</code>, <code>38</code>, <code>15</code>, and <code>77</code>. Adding these together produces <code><em>142</em></code>.</p>
+</article>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<pre><code>two1nine
+<em>x</em>&lt;y&gt; &amp;
+</code></pre>
+<p>Adding these together produces <code><em>281</em></code>.</p>
+</article>
+</main>"#;
+
+    const MARKDOWN_PAGE: &str = "## --- Day 1: Trebuchet?! ---
+
+```
+1abc2
+treb7uchet
+```
+
+Adding these together produces *`142`*.
+
+## --- Part Two ---
+
+Adding these together produces *`281`*.
+";
+
+    #[test]
+    fn test_extract_html() {
+        let examples = extract(HTML_PAGE);
+        assert_eq!(
+            examples.blocks,
+            vec![
+                "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n".to_string(),
+                "two1nine\nx<y> &\n".to_string()
+            ]
+        );
+        // The second answer is for the second example.
+        assert_eq!(
+            examples.answers,
+            vec![
+                (Part::One, 0, "142".to_string()),
+                (Part::Two, 1, "281".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_extract_markdown() {
+        let examples = extract(MARKDOWN_PAGE);
+        assert_eq!(examples.blocks, vec!["1abc2\ntreb7uchet\n".to_string()]);
+        // Without a block of its own the second part is explained with the first example.
+        assert_eq!(
+            examples.answers,
+            vec![
+                (Part::One, 0, "142".to_string()),
+                (Part::Two, 0, "281".to_string())
+            ]
+        );
+    }
+}
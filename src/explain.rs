@@ -0,0 +1,154 @@
+//! Human-readable derivations of answers, see [`Solution::explain`](crate::solution::Solution).
+//!
+//! A [`Trace`] is a tree of steps, each with a label, an optional value and the steps it was
+//! derived from. It prints as indented text or as JSON, written by hand so explaining doesn't
+//! depend on the `batch` feature.
+
+use std::fmt::{Display, Write};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trace {
+    pub label: String,
+    pub value: Option<String>,
+    pub steps: Vec<Trace>,
+}
+
+impl Trace {
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            value: None,
+            steps: vec![],
+        }
+    }
+
+    pub fn value(mut self, value: impl Display) -> Self {
+        self.value = Some(value.to_string());
+        self
+    }
+
+    pub fn step(mut self, step: Trace) -> Self {
+        self.steps.push(step);
+        self
+    }
+
+    pub fn steps(mut self, steps: impl IntoIterator<Item = Trace>) -> Self {
+        self.steps.extend(steps);
+        self
+    }
+
+    /// One line per step as `label: value`, indented by two spaces per level.
+    pub fn text(&self) -> String {
+        let mut text = String::new();
+        self.write_text(&mut text, 0);
+        text
+    }
+
+    fn write_text(&self, text: &mut String, depth: usize) {
+        write!(text, "{:1$}{2}", "", depth * 2, self.label).unwrap();
+        if let Some(value) = &self.value {
+            write!(text, ": {}", value).unwrap();
+        }
+        text.push('\n');
+        for step in self.steps.iter() {
+            step.write_text(text, depth + 1);
+        }
+    }
+
+    /// The trace as a JSON object with the fields `label`, `value` and `steps`. Values stay
+    /// strings, like the answers they lead to.
+    pub fn json(&self) -> String {
+        let mut json = String::new();
+        self.write_json(&mut json, 0);
+        json.push('\n');
+        json
+    }
+
+    fn write_json(&self, json: &mut String, depth: usize) {
+        let indent = "  ".repeat(depth + 1);
+        let value = self.value.as_deref().map_or("null".to_string(), quote);
+        write!(
+            json,
+            "{{\n{0}\"label\": {1},\n{0}\"value\": {2},\n{0}\"steps\": [",
+            indent,
+            quote(&self.label),
+            value
+        )
+        .unwrap();
+        for (i, step) in self.steps.iter().enumerate() {
+            json.push_str(if i == 0 { "\n" } else { ",\n" });
+            write!(json, "{}  ", indent).unwrap();
+            step.write_json(json, depth + 2);
+        }
+        if !self.steps.is_empty() {
+            write!(json, "\n{}", indent).unwrap();
+        }
+        write!(json, "]\n{}}}", "  ".repeat(depth)).unwrap();
+    }
+}
+
+fn quote(s: &str) -> String {
+    let mut quoted = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\t' => quoted.push_str("\\t"),
+            c if c.is_control() => write!(quoted, "\\u{:04x}", c as u32).unwrap(),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trace() -> Trace {
+        Trace::new("sum")
+            .value(3)
+            .step(Trace::new("a \"quoted\"\tline").value(1))
+            .step(Trace::new("b").value(2).step(Trace::new("no value")))
+    }
+
+    #[test]
+    fn test_text() {
+        assert_eq!(
+            trace().text(),
+            "sum: 3\n  a \"quoted\"\tline: 1\n  b: 2\n    no value\n"
+        );
+    }
+
+    #[test]
+    fn test_json() {
+        assert_eq!(
+            trace().json(),
+            r#"{
+  "label": "sum",
+  "value": "3",
+  "steps": [
+    {
+      "label": "a \"quoted\"\tline",
+      "value": "1",
+      "steps": []
+    },
+    {
+      "label": "b",
+      "value": "2",
+      "steps": [
+        {
+          "label": "no value",
+          "value": null,
+          "steps": []
+        }
+      ]
+    }
+  ]
+}
+"#
+        );
+    }
+}
@@ -0,0 +1,289 @@
+//! A small C ABI for calling the solvers in-process from other languages. The matching header is
+//! `include/aoc.h`, generated by [`header`].
+
+use std::{
+    cell::Cell,
+    ffi::{c_char, CStr},
+    panic::{self, AssertUnwindSafe},
+    slice,
+    sync::Once,
+};
+
+use crate::{
+    input,
+    solution::{Part, Solution},
+    years,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(i32)]
+pub enum Status {
+    Ok = 0,
+    /// There's no solver for the requested day.
+    UnknownDay = 1,
+    /// The part isn't 1 or 2.
+    UnknownPart = 2,
+    /// The day exists but the requested part hasn't been solved yet.
+    Unsolved = 3,
+    /// The input isn't valid UTF-8.
+    InvalidUtf8 = 4,
+    /// The input is malformed, see [`Solution::parse`](crate::solution::Solution::parse).
+    InvalidInput = 5,
+    /// The answer doesn't fit into the output buffer, the required length was written to
+    /// `out_len`.
+    BufferTooSmall = 6,
+    /// A pointer argument was NULL.
+    NullPointer = 7,
+    /// There are no solvers for the requested year.
+    UnknownYear = 8,
+    /// The solver panicked while checking the input or solving an input it accepted, which is a
+    /// bug in the solver.
+    InternalError = 9,
+}
+
+impl Status {
+    pub const ALL: [Status; 10] = [
+        Status::Ok,
+        Status::UnknownDay,
+        Status::UnknownPart,
+        Status::Unsolved,
+        Status::InvalidUtf8,
+        Status::InvalidInput,
+        Status::BufferTooSmall,
+        Status::NullPointer,
+        Status::UnknownYear,
+        Status::InternalError,
+    ];
+
+    fn c_name(&self) -> &'static str {
+        match self {
+            Status::Ok => "AOC_OK",
+            Status::UnknownDay => "AOC_UNKNOWN_DAY",
+            Status::UnknownPart => "AOC_UNKNOWN_PART",
+            Status::Unsolved => "AOC_UNSOLVED",
+            Status::InvalidUtf8 => "AOC_INVALID_UTF8",
+            Status::InvalidInput => "AOC_INVALID_INPUT",
+            Status::BufferTooSmall => "AOC_BUFFER_TOO_SMALL",
+            Status::NullPointer => "AOC_NULL_POINTER",
+            Status::UnknownYear => "AOC_UNKNOWN_YEAR",
+            Status::InternalError => "AOC_INTERNAL_ERROR",
+        }
+    }
+
+    fn message(&self) -> &'static CStr {
+        match self {
+            Status::Ok => c"ok",
+            Status::UnknownDay => c"no solver for this day",
+            Status::UnknownPart => c"part must be 1 or 2",
+            Status::Unsolved => c"part isn't solved yet",
+            Status::InvalidUtf8 => c"input isn't valid UTF-8",
+            Status::InvalidInput => c"solver rejected the input",
+            Status::BufferTooSmall => c"output buffer too small",
+            Status::NullPointer => c"unexpected NULL pointer",
+            Status::UnknownYear => c"no solvers for this year",
+            Status::InternalError => c"solver failed on a valid input",
+        }
+    }
+}
+
+fn solve(year: u16, day: u8, part: u8, input: &[u8]) -> Result<String, Status> {
+    let year = years::get(year).ok_or(Status::UnknownYear)?;
+    let solution = year.get(day).ok_or(Status::UnknownDay)?;
+    let part = Part::from_number(part).ok_or(Status::UnknownPart)?;
+    let input = std::str::from_utf8(input).map_err(|_| Status::InvalidUtf8)?;
+    let input = input::normalize(input).text;
+    solve_checked(solution, part, &input)
+}
+
+/// Checks `input` with the parse hook and solves it, turning a panic in either into
+/// [`Status::InternalError`].
+fn solve_checked(solution: &dyn Solution, part: Part, input: &str) -> Result<String, Status> {
+    // Unwinding into C would be undefined behaviour, and the panic message would end up on the
+    // host's stderr.
+    silence_panics();
+    QUIET.with(|quiet| quiet.set(true));
+    let answer = panic::catch_unwind(AssertUnwindSafe(|| {
+        solution
+            .parse(part, input)
+            .map_err(|_| Status::InvalidInput)?;
+        solution.solve(part, input).ok_or(Status::Unsolved)
+    }));
+    QUIET.with(|quiet| quiet.set(false));
+    answer.map_err(|_| Status::InternalError)?
+}
+
+thread_local! {
+    /// Whether a solver is running on behalf of a C caller on this thread.
+    static QUIET: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Wraps the panic hook once so panics are silent while [`QUIET`] is set. Other panics still go
+/// to the hook the host installed.
+fn silence_panics() {
+    static HOOK: Once = Once::new();
+    HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !QUIET.with(Cell::get) {
+                previous(info);
+            }
+        }));
+    });
+}
+
+/// Solves `part` of `day` in `year` for the `input_len` bytes at `input`. The answer is written to `out` as
+/// a NUL-terminated string, `out_len` holds the capacity of `out` on entry and the length of the
+/// answer without the NUL byte on return.
+///
+/// # Safety
+///
+/// `input` must point to `input_len` readable bytes, `out` to `*out_len` writable bytes and
+/// `out_len` must be a valid pointer. `input` may be NULL if `input_len` is 0.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve(
+    year: u16,
+    day: u8,
+    part: u8,
+    input: *const u8,
+    input_len: usize,
+    out: *mut c_char,
+    out_len: *mut usize,
+) -> Status {
+    if (input.is_null() && input_len > 0) || out.is_null() || out_len.is_null() {
+        return Status::NullPointer;
+    }
+
+    let input = if input.is_null() {
+        &[]
+    } else {
+        slice::from_raw_parts(input, input_len)
+    };
+    let answer = match solve(year, day, part, input) {
+        Ok(answer) => answer,
+        Err(status) => return status,
+    };
+
+    let capacity = *out_len;
+    *out_len = answer.len();
+    if answer.len() + 1 > capacity {
+        return Status::BufferTooSmall;
+    }
+    let out = slice::from_raw_parts_mut(out as *mut u8, capacity);
+    out[..answer.len()].copy_from_slice(answer.as_bytes());
+    out[answer.len()] = 0;
+
+    Status::Ok
+}
+
+/// Returns a static description of `status`.
+#[no_mangle]
+pub extern "C" fn aoc_status_message(status: i32) -> *const c_char {
+    Status::ALL
+        .iter()
+        .find(|s| **s as i32 == status)
+        .map_or(c"unknown status", |s| s.message())
+        .as_ptr()
+}
+
+/// The C header declaring the functions and status codes of this module.
+pub fn header() -> String {
+    let mut header = String::from(
+        "/* Generated by `cargo run --bin aoc -- ffi-header`, do not edit. */
+
+#ifndef AOC_H
+#define AOC_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+",
+    );
+
+    for status in Status::ALL {
+        header.push_str(&format!(
+            "#define {} {} /* {} */\n",
+            status.c_name(),
+            status as i32,
+            status.message().to_str().unwrap()
+        ));
+    }
+
+    header.push_str(
+        "
+/*
+ * Solves `part` of `day` in `year` for the `input_len` bytes at `input`. The answer is written to
+ * `out` as a NUL-terminated string, `out_len` holds the capacity of `out` on entry and the length
+ * of the answer without the NUL byte on return. `input` may be NULL if `input_len` is 0. Returns
+ * one of the status codes above.
+ */
+int32_t aoc_solve(uint16_t year, uint8_t day, uint8_t part, const uint8_t *input, size_t input_len,
+                  char *out, size_t *out_len);
+
+/* Returns a static description of a status code. */
+const char *aoc_status_message(int32_t status);
+
+#endif
+",
+    );
+
+    header
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Its parser panics on inputs starting with `!`.
+    struct Fragile;
+
+    impl Solution for Fragile {
+        fn day(&self) -> u8 {
+            1
+        }
+
+        fn part_1(&self, input: &str) -> String {
+            input.len().to_string()
+        }
+
+        fn part_2(&self, _input: &str) -> Option<String> {
+            None
+        }
+
+        fn parse(&self, _part: Part, input: &str) -> Result<(), String> {
+            assert!(!input.starts_with('!'), "unexpected !");
+            if input.is_empty() {
+                return Err("empty input".to_string());
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_solve_catches_parser_panics() {
+        assert_eq!(
+            solve_checked(&Fragile, Part::One, "abc"),
+            Ok("3".to_string())
+        );
+        assert_eq!(
+            solve_checked(&Fragile, Part::Two, "abc"),
+            Err(Status::Unsolved)
+        );
+        assert_eq!(
+            solve_checked(&Fragile, Part::One, ""),
+            Err(Status::InvalidInput)
+        );
+        assert_eq!(
+            solve_checked(&Fragile, Part::One, "!"),
+            Err(Status::InternalError)
+        );
+    }
+
+    #[test]
+    fn test_header_is_up_to_date() {
+        assert_eq!(
+            include_str!("../include/aoc.h"),
+            header(),
+            "run `cargo run --bin aoc -- ffi-header > include/aoc.h`"
+        );
+    }
+}
@@ -0,0 +1,335 @@
+//! Directed graphs with string labelled nodes and arbitrary edge labels.
+//!
+//! Nodes are interned by their label and referred to by dense [`NodeId`]s, so solvers can keep
+//! per-node state in plain vectors. Edges are stored in both directions, which makes predecessor
+//! lookups as cheap as successor lookups.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt::{self, Write},
+};
+
+use crate::bitgrid::BitSet;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NodeId(usize);
+
+impl NodeId {
+    pub fn index(&self) -> usize {
+        self.0
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Edge<E> {
+    pub from: NodeId,
+    pub to: NodeId,
+    pub label: E,
+}
+
+#[derive(Debug, Clone)]
+pub struct Graph<E> {
+    labels: Vec<String>,
+    ids: HashMap<String, NodeId>,
+    edges: Vec<Edge<E>>,
+    /// Indices into `edges` per node, in insertion order.
+    outgoing: Vec<Vec<usize>>,
+    incoming: Vec<Vec<usize>>,
+}
+
+impl<E> Default for Graph<E> {
+    fn default() -> Self {
+        Self {
+            labels: vec![],
+            ids: HashMap::new(),
+            edges: vec![],
+            outgoing: vec![],
+            incoming: vec![],
+        }
+    }
+}
+
+impl<E> Graph<E> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The node with the given label, which is added if it doesn't exist yet.
+    pub fn node(&mut self, label: &str) -> NodeId {
+        if let Some(id) = self.ids.get(label) {
+            return *id;
+        }
+        let id = NodeId(self.labels.len());
+        self.labels.push(label.to_owned());
+        self.ids.insert(label.to_owned(), id);
+        self.outgoing.push(vec![]);
+        self.incoming.push(vec![]);
+        id
+    }
+
+    /// Adds an edge, adding its nodes as needed.
+    pub fn add_edge(&mut self, from: &str, to: &str, label: E) {
+        let (from, to) = (self.node(from), self.node(to));
+        self.outgoing[from.0].push(self.edges.len());
+        self.incoming[to.0].push(self.edges.len());
+        self.edges.push(Edge { from, to, label });
+    }
+
+    pub fn id(&self, label: &str) -> Option<NodeId> {
+        self.ids.get(label).copied()
+    }
+
+    pub fn label(&self, node: NodeId) -> &str {
+        &self.labels[node.0]
+    }
+
+    /// The number of nodes.
+    pub fn len(&self) -> usize {
+        self.labels.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty()
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = NodeId> {
+        (0..self.len()).map(NodeId)
+    }
+
+    pub fn edges(&self) -> &[Edge<E>] {
+        &self.edges
+    }
+
+    /// The outgoing edges of `node`, in the order they were added.
+    pub fn successors(&self, node: NodeId) -> impl Iterator<Item = &Edge<E>> {
+        self.outgoing[node.0].iter().map(|i| &self.edges[*i])
+    }
+
+    /// The incoming edges of `node`, in the order they were added.
+    pub fn predecessors(&self, node: NodeId) -> impl Iterator<Item = &Edge<E>> {
+        self.incoming[node.0].iter().map(|i| &self.edges[*i])
+    }
+
+    /// The same graph with every edge label mapped by `f`.
+    pub fn map_edges<F>(self, mut f: impl FnMut(E) -> F) -> Graph<F> {
+        Graph {
+            labels: self.labels,
+            ids: self.ids,
+            edges: self
+                .edges
+                .into_iter()
+                .map(|e| Edge {
+                    from: e.from,
+                    to: e.to,
+                    label: f(e.label),
+                })
+                .collect(),
+            outgoing: self.outgoing,
+            incoming: self.incoming,
+        }
+    }
+
+    /// The nodes reachable from `start`, including `start` itself, indexed by [`NodeId::index`].
+    pub fn reachable(&self, start: NodeId) -> BitSet {
+        let mut reached = BitSet::new(self.len());
+        reached.set(start.0, true);
+        let mut queue = VecDeque::from([start]);
+        while let Some(node) = queue.pop_front() {
+            for edge in self.successors(node) {
+                if !reached.get(edge.to.0) {
+                    reached.set(edge.to.0, true);
+                    queue.push_back(edge.to);
+                }
+            }
+        }
+        reached
+    }
+
+    /// The strongly connected components, in topological order of the condensed graph: no
+    /// component has an edge to an earlier one.
+    ///
+    /// This is Tarjan's algorithm with an explicit stack, so deep graphs can't overflow the call
+    /// stack.
+    pub fn strongly_connected_components(&self) -> Vec<Vec<NodeId>> {
+        const UNVISITED: usize = usize::MAX;
+        let mut index = vec![UNVISITED; self.len()];
+        let mut low_link = vec![0; self.len()];
+        let mut on_stack = vec![false; self.len()];
+        let mut stack = vec![];
+        let mut components = vec![];
+        let mut next_index = 0;
+
+        for root in self.nodes() {
+            if index[root.0] != UNVISITED {
+                continue;
+            }
+            // Each frame is a node and the position of the next outgoing edge to look at.
+            let mut frames = vec![(root, 0)];
+            while let Some((node, edge)) = frames.pop() {
+                if edge == 0 {
+                    index[node.0] = next_index;
+                    low_link[node.0] = next_index;
+                    next_index += 1;
+                    stack.push(node);
+                    on_stack[node.0] = true;
+                }
+
+                if let Some(next) = self.outgoing[node.0].get(edge).map(|e| self.edges[*e].to) {
+                    frames.push((node, edge + 1));
+                    if index[next.0] == UNVISITED {
+                        frames.push((next, 0));
+                    } else if on_stack[next.0] {
+                        low_link[node.0] = low_link[node.0].min(index[next.0]);
+                    }
+                    continue;
+                }
+
+                if low_link[node.0] == index[node.0] {
+                    let mut component = vec![];
+                    loop {
+                        let member = stack.pop().unwrap();
+                        on_stack[member.0] = false;
+                        component.push(member);
+                        if member == node {
+                            break;
+                        }
+                    }
+                    component.reverse();
+                    components.push(component);
+                }
+                if let Some((parent, _)) = frames.last() {
+                    low_link[parent.0] = low_link[parent.0].min(low_link[node.0]);
+                }
+            }
+        }
+
+        // Tarjan's algorithm finds the components in reverse topological order.
+        components.reverse();
+        components
+    }
+
+    /// The nodes ordered so that every edge points forward, or `None` if the graph has a cycle.
+    /// Ties are broken by insertion order.
+    pub fn topological_order(&self) -> Option<Vec<NodeId>> {
+        let mut in_degrees = self.incoming.iter().map(Vec::len).collect::<Vec<_>>();
+        let mut queue = self
+            .nodes()
+            .filter(|n| in_degrees[n.0] == 0)
+            .collect::<VecDeque<_>>();
+
+        let mut order = Vec::with_capacity(self.len());
+        while let Some(node) = queue.pop_front() {
+            order.push(node);
+            for edge in self.successors(node) {
+                in_degrees[edge.to.0] -= 1;
+                if in_degrees[edge.to.0] == 0 {
+                    queue.push_back(edge.to);
+                }
+            }
+        }
+
+        (order.len() == self.len()).then_some(order)
+    }
+}
+
+impl<E: fmt::Display> Graph<E> {
+    /// The graph in Graphviz DOT format, e.g. for `dot -Tsvg`. Empty edge labels are left out.
+    pub fn dot(&self) -> String {
+        let mut dot = "digraph {\n".to_string();
+        for node in self.nodes() {
+            writeln!(dot, "    {};", quote(self.label(node))).unwrap();
+        }
+        for edge in self.edges.iter() {
+            let (from, to) = (quote(self.label(edge.from)), quote(self.label(edge.to)));
+            let label = edge.label.to_string();
+            if label.is_empty() {
+                writeln!(dot, "    {} -> {};", from, to).unwrap();
+            } else {
+                writeln!(dot, "    {} -> {} [label={}];", from, to, quote(&label)).unwrap();
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+fn quote(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn graph(edges: &[(&str, &str)]) -> Graph<String> {
+        let mut graph = Graph::new();
+        for (from, to) in edges {
+            graph.add_edge(from, to, String::new());
+        }
+        graph
+    }
+
+    fn labels(graph: &Graph<String>, nodes: &[NodeId]) -> Vec<String> {
+        nodes.iter().map(|n| graph.label(*n).to_owned()).collect()
+    }
+
+    #[test]
+    fn test_interning_and_predecessors() {
+        let mut graph = Graph::new();
+        graph.add_edge("a", "b", 1);
+        graph.add_edge("c", "b", 2);
+        graph.add_edge("b", "a", 3);
+
+        assert_eq!(graph.len(), 3);
+        let b = graph.id("b").unwrap();
+        let sources = graph
+            .predecessors(b)
+            .map(|e| (graph.label(e.from), e.label))
+            .collect::<Vec<_>>();
+        assert_eq!(sources, [("a", 1), ("c", 2)]);
+        assert_eq!(graph.successors(b).count(), 1);
+
+        let reached = graph.reachable(graph.id("a").unwrap());
+        assert_eq!(reached.iter_ones().collect::<Vec<_>>(), [0, 1]);
+    }
+
+    #[test]
+    fn test_components_and_order() {
+        let graph = graph(&[
+            ("in", "a"),
+            ("a", "b"),
+            ("b", "a"),
+            ("b", "out"),
+            ("in", "out"),
+        ]);
+        let components = graph
+            .strongly_connected_components()
+            .iter()
+            .map(|c| labels(&graph, c))
+            .collect::<Vec<_>>();
+        assert_eq!(components, [vec!["in"], vec!["a", "b"], vec!["out"]]);
+        assert_eq!(graph.topological_order(), None);
+
+        let graph = self::graph(&[("in", "a"), ("a", "out"), ("in", "b"), ("b", "a")]);
+        let order = graph.topological_order().unwrap();
+        assert_eq!(labels(&graph, &order), ["in", "b", "a", "out"]);
+    }
+
+    #[test]
+    fn test_dot() {
+        let mut graph = Graph::new();
+        graph.add_edge("a", "say \"hi\"", "L");
+        graph.add_edge("a", "b", "");
+        assert_eq!(
+            graph.dot(),
+            "digraph {
+    \"a\";
+    \"say \\\"hi\\\"\";
+    \"b\";
+    \"a\" -> \"say \\\"hi\\\"\" [label=\"L\"];
+    \"a\" -> \"b\";
+}
+"
+        );
+    }
+}
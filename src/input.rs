@@ -0,0 +1,298 @@
+//! Loading and normalising puzzle inputs.
+
+use std::{
+    borrow::Cow,
+    fmt,
+    fs::{self, read_dir},
+    io,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+pub fn path(year: u16, day: u8) -> PathBuf {
+    PathBuf::from(format!("inputs/{}/day{:02}.txt", year, day))
+}
+
+/// Every encrypted input starts with this line.
+pub const ENCRYPTED_MAGIC: &[u8] = b"aoc-encrypted-v1\n";
+
+/// Reads an input file, decrypting it if it was encrypted with `aoc inputs encrypt`, and
+/// normalises it, see [`normalize`]. Prints a warning if normalising changed the input.
+///
+/// Plaintext files are read as they are, so a key is only needed for encrypted inputs.
+pub fn read(path: &Path) -> io::Result<String> {
+    let mut data = fs::read(path)?;
+    if is_encrypted(&data) {
+        data = decrypt(path, &data)?;
+    }
+
+    let raw = String::from_utf8(data)
+        .map_err(|_| invalid_data(format!("{} isn't UTF-8", path.display())))?;
+    let normalized = normalize(&raw);
+    for change in normalized.changes.iter() {
+        eprintln!("warning: {}: {}", path.display(), change);
+    }
+
+    Ok(normalized.text.into_owned())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Change {
+    RemovedByteOrderMark,
+    ConvertedLineEndings,
+    RemovedTrailingWhitespace,
+    RemovedTrailingBlankLines,
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let description = match self {
+            Change::RemovedByteOrderMark => "removed byte order mark",
+            Change::ConvertedLineEndings => "converted CRLF/CR line endings to LF",
+            Change::RemovedTrailingWhitespace => "removed trailing whitespace",
+            Change::RemovedTrailingBlankLines => "removed blank lines at the end",
+        };
+        write!(f, "{}", description)
+    }
+}
+
+#[derive(Debug)]
+pub struct Normalized<'a> {
+    pub text: Cow<'a, str>,
+    pub changes: Vec<Change>,
+}
+
+/// Brings an input into the shape the solvers expect: no byte order mark, `\n` line endings, no
+/// whitespace at the end of lines and no blank lines at the end of the input. Whether the input
+/// ends with a newline is kept as it is.
+pub fn normalize(raw: &str) -> Normalized<'_> {
+    let mut changes = vec![];
+
+    let text = match raw.strip_prefix('\u{feff}') {
+        Some(rest) => {
+            changes.push(Change::RemovedByteOrderMark);
+            rest
+        }
+        None => raw,
+    };
+
+    let unified = if text.contains('\r') {
+        changes.push(Change::ConvertedLineEndings);
+        Cow::Owned(text.replace("\r\n", "\n").replace('\r', "\n"))
+    } else {
+        Cow::Borrowed(text)
+    };
+
+    let ends_with_newline = unified.ends_with('\n');
+    let mut lines = unified.split('\n').collect::<Vec<_>>();
+    if ends_with_newline {
+        lines.pop();
+    }
+
+    let trimmed = lines.iter().map(|line| line.trim_end()).collect::<Vec<_>>();
+    if trimmed
+        .iter()
+        .zip(lines.iter())
+        .any(|(t, l)| t.len() != l.len())
+    {
+        changes.push(Change::RemovedTrailingWhitespace);
+    }
+    let mut lines = trimmed;
+
+    if lines.last().is_some_and(|line| line.is_empty()) {
+        changes.push(Change::RemovedTrailingBlankLines);
+        while lines.last().is_some_and(|line| line.is_empty()) {
+            lines.pop();
+        }
+    }
+
+    if changes.is_empty() {
+        return Normalized {
+            text: Cow::Borrowed(raw),
+            changes,
+        };
+    }
+
+    let mut text = lines.join("\n");
+    if ends_with_newline {
+        text.push('\n');
+    }
+    Normalized {
+        text: Cow::Owned(text),
+        changes,
+    }
+}
+
+/// Parses a number, with an error naming the text that isn't one.
+pub fn number<T: FromStr>(s: &str) -> Result<T, String> {
+    s.parse()
+        .map_err(|_| format!("expected a number, got '{}'", s))
+}
+
+/// [`str::split_once`] with an error if `separator` is missing.
+pub fn split_once<'a>(s: &'a str, separator: &str) -> Result<(&'a str, &'a str), String> {
+    s.split_once(separator)
+        .ok_or_else(|| format!("expected '{}' in '{}'", separator, s))
+}
+
+/// Lines without trailing whitespace, so stray `\r`s don't end up in the parsed values.
+pub fn lines(input: &str) -> impl Iterator<Item = &str> {
+    input.lines().map(str::trim_end)
+}
+
+/// Splits the input into blocks separated by one or more blank lines. Lines consisting only of
+/// whitespace count as blank and empty blocks are skipped.
+pub fn blocks(input: &str) -> impl Iterator<Item = &str> {
+    let mut rest = input;
+
+    std::iter::from_fn(move || {
+        // Skip blank lines in front of the block.
+        loop {
+            let (line, after) = rest.split_once('\n').unwrap_or((rest, ""));
+            if rest.is_empty() || !line.trim().is_empty() {
+                break;
+            }
+            rest = after;
+        }
+        if rest.is_empty() {
+            return None;
+        }
+
+        let mut end = 0;
+        let mut remaining = rest;
+        while !remaining.is_empty() {
+            let (line, after) = remaining.split_once('\n').unwrap_or((remaining, ""));
+            if line.trim().is_empty() {
+                break;
+            }
+            end += remaining.len() - after.len();
+            remaining = after;
+        }
+
+        let block = rest[..end].trim_end();
+        rest = remaining;
+        Some(block)
+    })
+}
+
+pub fn is_encrypted(data: &[u8]) -> bool {
+    data.starts_with(ENCRYPTED_MAGIC)
+}
+
+#[cfg(feature = "encryption")]
+fn decrypt(path: &Path, data: &[u8]) -> io::Result<Vec<u8>> {
+    use crate::encryption::{self, Key};
+
+    let key = Key::load()?.ok_or_else(|| {
+        invalid_data(format!(
+            "{} is encrypted but no key is configured",
+            path.display()
+        ))
+    })?;
+    encryption::decrypt(&key, data).map_err(|e| invalid_data(format!("{}: {}", path.display(), e)))
+}
+
+#[cfg(not(feature = "encryption"))]
+fn decrypt(path: &Path, _data: &[u8]) -> io::Result<Vec<u8>> {
+    Err(invalid_data(format!(
+        "{} is encrypted but encryption support isn't enabled",
+        path.display()
+    )))
+}
+
+pub(crate) fn invalid_data(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputFile {
+    /// Directory of the file relative to the root, `.` for files directly inside the root.
+    pub user: String,
+    pub day: u8,
+    pub path: PathBuf,
+}
+
+/// Parses file names of the form `dayNN.txt`.
+fn parse_day(file_name: &str) -> Option<u8> {
+    let digits = file_name.strip_prefix("day")?.strip_suffix(".txt")?;
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    digits.parse().ok()
+}
+
+/// Recursively collects all `dayNN.txt` files below `root`, sorted by user and day.
+pub fn discover(root: &Path) -> io::Result<Vec<InputFile>> {
+    fn walk(root: &Path, dir: &Path, result: &mut Vec<InputFile>) -> io::Result<()> {
+        for entry in read_dir(dir)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                walk(root, &path, result)?;
+                continue;
+            }
+
+            let day = path.file_name().and_then(|n| n.to_str()).and_then(parse_day);
+            if let Some(day) = day {
+                let user = path
+                    .parent()
+                    .and_then(|p| p.strip_prefix(root).ok())
+                    .map(|p| p.to_string_lossy().into_owned())
+                    .filter(|p| !p.is_empty())
+                    .unwrap_or_else(|| ".".to_string());
+                result.push(InputFile { user, day, path });
+            }
+        }
+        Ok(())
+    }
+
+    let mut result = vec![];
+    walk(root, root, &mut result)?;
+    result.sort_by(|a, b| (&a.user, a.day).cmp(&(&b.user, b.day)));
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize() {
+        let normalized = normalize("abc\ndef\n");
+        assert!(matches!(normalized.text, Cow::Borrowed(_)));
+        assert!(normalized.changes.is_empty());
+
+        let normalized = normalize("\u{feff}abc  \r\n\r\ndef\r\n\r\n\r\n");
+        assert_eq!(normalized.text, "abc\n\ndef\n");
+        assert_eq!(
+            normalized.changes,
+            vec![
+                Change::RemovedByteOrderMark,
+                Change::ConvertedLineEndings,
+                Change::RemovedTrailingWhitespace,
+                Change::RemovedTrailingBlankLines,
+            ]
+        );
+
+        assert_eq!(normalize("abc\t").text, "abc");
+    }
+
+    #[test]
+    fn test_blocks() {
+        let blocks = |input| super::blocks(input).collect::<Vec<_>>();
+
+        assert_eq!(blocks("a\nb\n\nc\n"), vec!["a\nb", "c"]);
+        assert_eq!(blocks("a\r\nb\r\n\r\nc\r\n"), vec!["a\r\nb", "c"]);
+        assert_eq!(blocks("\n\na\n \n\n\nb\n\n\n"), vec!["a", "b"]);
+        assert_eq!(blocks("a"), vec!["a"]);
+        assert!(blocks("").is_empty());
+    }
+
+    #[test]
+    fn test_parse_day() {
+        assert_eq!(parse_day("day01.txt"), Some(1));
+        assert_eq!(parse_day("day19.txt"), Some(19));
+        assert_eq!(parse_day("day.txt"), None);
+        assert_eq!(parse_day("day01.txt.bak"), None);
+        assert_eq!(parse_day("notes.txt"), None);
+    }
+}
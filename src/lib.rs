@@ -0,0 +1,26 @@
+pub mod alloc;
+pub mod answers;
+#[cfg(feature = "batch")]
+pub mod batch;
+pub mod bitgrid;
+pub mod cancel;
+pub mod compress;
+#[cfg(feature = "encryption")]
+pub mod encryption;
+pub mod examples;
+pub mod explain;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub mod graph;
+pub mod input;
+pub mod parallel;
+pub mod profile;
+pub mod report;
+pub mod runner;
+pub mod search;
+pub mod solution;
+pub mod years;
+
+#[cfg(test)]
+#[global_allocator]
+static ALLOCATOR: alloc::CountingAllocator = alloc::CountingAllocator;
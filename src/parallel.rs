@@ -0,0 +1,240 @@
+//! Solves many days at once on a pool of scoped threads and summarizes them in a table, ordered
+//! by day no matter which day finished first.
+//!
+//! Every part still goes through [`runner::run_part`], so a panic or a timeout only fails that
+//! part and the other days carry on.
+
+use std::{
+    fmt::Write,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    thread,
+};
+
+use crate::{
+    report::format_duration,
+    runner::{self, Limits, Outcome, Run},
+    solution::{Part, Solution},
+};
+
+#[derive(Debug, Clone)]
+pub struct DayRun {
+    pub day: u8,
+    /// The runs of both parts, or why the input couldn't be read.
+    pub parts: Result<[Run; 2], String>,
+}
+
+/// Solves both parts of every day in `solutions` on up to `threads` worker threads. `input`
+/// provides the puzzle input of a day and is called on the worker threads as well.
+///
+/// The results are ordered by day.
+pub fn run_days(
+    solutions: &[&'static dyn Solution],
+    input: impl Fn(u8) -> Result<String, String> + Sync,
+    limits: &Limits,
+    threads: usize,
+) -> Vec<DayRun> {
+    let next = AtomicUsize::new(0);
+    let runs = Mutex::new(Vec::with_capacity(solutions.len()));
+
+    thread::scope(|scope| {
+        for _ in 0..threads.clamp(1, solutions.len().max(1)) {
+            scope.spawn(|| {
+                // Workers take the next day until there are none left, so a slow day doesn't
+                // hold up the ones queued behind it.
+                while let Some(solution) = solutions.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let parts = input(solution.day()).map(|input| {
+                        let input = Arc::<str>::from(input);
+                        Part::ALL
+                            .map(|part| runner::run_part(*solution, part, input.clone(), limits))
+                    });
+                    let run = DayRun {
+                        day: solution.day(),
+                        parts,
+                    };
+                    runs.lock().unwrap().push(run);
+                }
+            });
+        }
+    });
+
+    let mut runs = runs.into_inner().unwrap();
+    runs.sort_by_key(|run| run.day);
+    runs
+}
+
+/// The answer or failure of a part and how long it took, both empty if the part isn't solved.
+fn cells(run: &Run) -> [String; 2] {
+    if run.outcome == Outcome::Unsolved {
+        return [String::new(), String::new()];
+    }
+    // Panic messages can span several lines, which would break the table.
+    let outcome = run.outcome.to_string();
+    let outcome = outcome.lines().next().unwrap_or_default().to_string();
+    [outcome, format_duration(run.elapsed)]
+}
+
+/// A plain text table with the answers and times of both parts of every day.
+pub fn table(runs: &[DayRun]) -> String {
+    let mut rows = vec![(
+        0,
+        Ok(["Part 1", "Time", "Part 2", "Time"].map(String::from)),
+    )];
+    for run in runs {
+        rows.push((
+            run.day,
+            run.parts.as_ref().map(|[one, two]| {
+                let ([answer_1, time_1], [answer_2, time_2]) = (cells(one), cells(two));
+                [answer_1, time_1, answer_2, time_2]
+            }),
+        ));
+    }
+
+    // Days without input span the whole row, so they don't count towards the column widths.
+    let mut widths = [0; 4];
+    for (_, row) in rows.iter() {
+        for (width, cell) in widths.iter_mut().zip(row.iter().flatten()) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let mut table = String::new();
+    for (day, row) in rows {
+        let mut line = match day {
+            0 => "Day  ".to_string(),
+            day => format!("{:02}   ", day),
+        };
+        match row {
+            Ok(cells) => {
+                for (column, (cell, width)) in cells.iter().zip(widths).enumerate() {
+                    // The times are right-aligned so their units line up.
+                    if column % 2 == 1 {
+                        write!(line, "{:>width$}  ", cell, width = width).unwrap();
+                    } else {
+                        write!(line, "{:<width$}  ", cell, width = width).unwrap();
+                    }
+                }
+            }
+            Err(e) => write!(line, "no input: {}", e).unwrap(),
+        }
+        writeln!(table, "{}", line.trim_end()).unwrap();
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::cancel;
+
+    struct Slow;
+
+    impl Solution for Slow {
+        fn day(&self) -> u8 {
+            1
+        }
+
+        fn part_1(&self, input: &str) -> String {
+            thread::sleep(Duration::from_millis(50));
+            input.len().to_string()
+        }
+
+        fn part_2(&self, _input: &str) -> Option<String> {
+            None
+        }
+    }
+
+    struct Broken;
+
+    impl Solution for Broken {
+        fn day(&self) -> u8 {
+            2
+        }
+
+        fn part_1(&self, _input: &str) -> String {
+            panic!("broken\nacross lines")
+        }
+
+        fn part_2(&self, input: &str) -> Option<String> {
+            Some(input.to_uppercase())
+        }
+    }
+
+    struct Endless;
+
+    impl Solution for Endless {
+        fn day(&self) -> u8 {
+            3
+        }
+
+        fn part_1(&self, _input: &str) -> String {
+            loop {
+                cancel::checkpoint();
+            }
+        }
+
+        fn part_2(&self, _input: &str) -> Option<String> {
+            None
+        }
+    }
+
+    #[test]
+    fn test_failures_stay_with_their_day() {
+        let solutions: [&'static dyn Solution; 3] = [&Endless, &Broken, &Slow];
+        let input = |day| match day {
+            3 => Err("missing".to_string()),
+            _ => Ok("abc".to_string()),
+        };
+        let limits = Limits {
+            time: Duration::from_millis(200),
+            memory: None,
+        };
+        let runs = run_days(&solutions, input, &limits, 4);
+
+        assert_eq!(runs.iter().map(|r| r.day).collect::<Vec<_>>(), [1, 2, 3]);
+        let outcomes = |run: &DayRun| run.parts.as_ref().unwrap().clone().map(|r| r.outcome);
+        assert_eq!(
+            outcomes(&runs[0]),
+            [Outcome::Solved("3".into()), Outcome::Unsolved]
+        );
+        assert_eq!(
+            outcomes(&runs[1]),
+            [
+                Outcome::Panicked("broken\nacross lines".into()),
+                Outcome::Solved("ABC".into())
+            ]
+        );
+        assert_eq!(runs[2].parts.as_ref().unwrap_err(), "missing");
+
+        let table = table(&runs);
+        let lines = table.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 4);
+        assert!(lines[0].starts_with("Day  Part 1"));
+        assert!(lines[1].starts_with("01   3 "));
+        assert!(lines[2].contains("panicked: broken  "));
+        assert!(lines[2].contains("ABC"));
+        assert_eq!(lines[3], "03   no input: missing");
+    }
+
+    #[test]
+    fn test_timeouts_stay_with_their_day() {
+        let limits = Limits {
+            time: Duration::from_millis(50),
+            memory: None,
+        };
+        let runs = run_days(&[&Endless, &Broken], |_| Ok(String::new()), &limits, 2);
+
+        assert_eq!(
+            runs[0].parts.as_ref().unwrap()[1].outcome,
+            Outcome::Solved("".into())
+        );
+        assert_eq!(
+            runs[1].parts.as_ref().unwrap()[0].outcome,
+            Outcome::TimedOut
+        );
+    }
+}
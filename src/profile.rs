@@ -0,0 +1,210 @@
+//! Opt-in profiling of solvers: wall time, allocations and allocated bytes per part, broken down
+//! into the phases solvers mark with [`phase`].
+//!
+//! Phases cost a thread-local lookup while no profile is being taken. Allocation counts need
+//! [`crate::alloc::CountingAllocator`] to be installed and stay zero otherwise. Nested phases are
+//! measured inclusively, so their times don't add up to the total.
+
+use std::{
+    cell::RefCell,
+    fmt,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    alloc,
+    report::format_duration,
+    solution::{Part, Solution},
+};
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Counters {
+    pub elapsed: Duration,
+    pub allocations: usize,
+    pub allocated_bytes: usize,
+}
+
+impl Counters {
+    fn add(&mut self, other: Counters) {
+        self.elapsed += other.elapsed;
+        self.allocations += other.allocations;
+        self.allocated_bytes += other.allocated_bytes;
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Phase {
+    pub name: &'static str,
+    /// How often the phase was entered.
+    pub calls: usize,
+    pub counters: Counters,
+}
+
+thread_local! {
+    /// The phases recorded so far, `None` while not profiling.
+    static PHASES: RefCell<Option<Vec<Phase>>> = const { RefCell::new(None) };
+}
+
+/// Measures the phase `name` until dropped.
+pub struct PhaseGuard {
+    name: &'static str,
+    start: Option<(Instant, (usize, usize))>,
+}
+
+/// Starts measuring the phase `name`, e.g. `let _phase = profile::phase("parse");`. Entering the
+/// same phase repeatedly accumulates its counters.
+pub fn phase(name: &'static str) -> PhaseGuard {
+    let profiling = PHASES.with(|phases| phases.borrow().is_some());
+    PhaseGuard {
+        name,
+        start: profiling.then(|| (Instant::now(), alloc::allocations())),
+    }
+}
+
+impl Drop for PhaseGuard {
+    fn drop(&mut self) {
+        let Some((start, (allocations, allocated_bytes))) = self.start else {
+            return;
+        };
+        let elapsed = start.elapsed();
+        let now = alloc::allocations();
+        let counters = Counters {
+            elapsed,
+            allocations: now.0 - allocations,
+            allocated_bytes: now.1 - allocated_bytes,
+        };
+
+        PHASES.with(|phases| {
+            let mut phases = phases.borrow_mut();
+            let Some(phases) = phases.as_mut() else {
+                return;
+            };
+            match phases.iter_mut().find(|p| p.name == self.name) {
+                Some(phase) => {
+                    phase.calls += 1;
+                    phase.counters.add(counters);
+                }
+                None => phases.push(Phase {
+                    name: self.name,
+                    calls: 1,
+                    counters,
+                }),
+            }
+        });
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Profile {
+    pub answer: Option<String>,
+    pub total: Counters,
+    pub peak_bytes: usize,
+    /// Phases in the order they were first entered.
+    pub phases: Vec<Phase>,
+}
+
+/// Solves `part` on the current thread while profiling it.
+pub fn run(solution: &dyn Solution, part: Part, input: &str) -> Profile {
+    // Reserved up front so recording the first phases doesn't show up in the counts.
+    PHASES.with(|phases| *phases.borrow_mut() = Some(Vec::with_capacity(16)));
+    alloc::reset(None);
+
+    let start = Instant::now();
+    let answer = solution.solve(part, input);
+    let elapsed = start.elapsed();
+    let (allocations, allocated_bytes) = alloc::allocations();
+    let peak_bytes = alloc::peak();
+
+    Profile {
+        answer,
+        total: Counters {
+            elapsed,
+            allocations,
+            allocated_bytes,
+        },
+        peak_bytes,
+        phases: PHASES
+            .with(|phases| phases.borrow_mut().take())
+            .unwrap_or_default(),
+    }
+}
+
+fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit + 1 < UNITS.len() {
+        value /= 1024.0;
+        unit += 1;
+    }
+    match unit {
+        0 => format!("{} B", bytes),
+        _ => format!("{:.1} {}", value, UNITS[unit]),
+    }
+}
+
+impl fmt::Display for Profile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let row = |f: &mut fmt::Formatter<'_>, name: &str, calls: String, c: &Counters| {
+            writeln!(
+                f,
+                "  {:<20} {:>10} {:>9} {:>12} allocations {:>11}",
+                name,
+                format_duration(c.elapsed),
+                calls,
+                c.allocations,
+                format_bytes(c.allocated_bytes)
+            )
+        };
+
+        row(f, "total", String::new(), &self.total)?;
+        for phase in &self.phases {
+            row(f, phase.name, format!("{}x", phase.calls), &phase.counters)?;
+        }
+        writeln!(f, "  peak memory {}", format_bytes(self.peak_bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Phased;
+
+    impl Solution for Phased {
+        fn day(&self) -> u8 {
+            0
+        }
+
+        fn part_1(&self, _input: &str) -> String {
+            for _ in 0..3 {
+                let _phase = phase("loop");
+                std::hint::black_box(vec![0u8; 100]);
+            }
+            "done".to_string()
+        }
+
+        fn part_2(&self, _input: &str) -> Option<String> {
+            None
+        }
+    }
+
+    #[test]
+    fn test_phases() {
+        let profile = run(&Phased, Part::One, "");
+        assert_eq!(profile.answer.as_deref(), Some("done"));
+
+        let [looped] = &profile.phases[..] else {
+            panic!("expected one phase, got {:?}", profile.phases);
+        };
+        assert_eq!(looped.name, "loop");
+        assert_eq!(looped.calls, 3);
+        assert_eq!(looped.counters.allocations, 3);
+        assert_eq!(looped.counters.allocated_bytes, 300);
+        assert!(profile.total.allocations >= 4);
+
+        // Outside of `run` phases aren't recorded.
+        drop(phase("ignored"));
+        assert!(PHASES.with(|phases| phases.borrow().is_none()));
+    }
+}
@@ -0,0 +1,284 @@
+//! Markdown report of the progress over all 25 days of a year: which parts are solved or stubbed,
+//! how long they take and whether their answers match the [`Answers`] registry.
+
+use std::{
+    fmt::{self, Write},
+    path::Path,
+    sync::Arc,
+    time::Duration,
+};
+
+use crate::{
+    answers::Answers,
+    input,
+    runner::{self, Limits, Outcome},
+    solution::{Part, Solution},
+    years::Year,
+};
+
+pub(crate) fn format_duration(d: Duration) -> String {
+    if d < Duration::from_secs(1) {
+        format!("{:.1} ms", d.as_secs_f64() * 1000.0)
+    } else {
+        format!("{:.2} s", d.as_secs_f64())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Status {
+    Solved,
+    /// The answer is hard-coded, see [`Solution::is_stub`].
+    Stub,
+    NotSolved,
+    Failed(Outcome),
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Solved => write!(f, "solved"),
+            Status::Stub => write!(f, "stub"),
+            Status::NotSolved => write!(f, "not solved"),
+            Status::Failed(outcome) => write!(f, "{}", outcome),
+        }
+    }
+}
+
+struct Row {
+    status: Status,
+    answer: Option<String>,
+    elapsed: Option<Duration>,
+}
+
+fn run_part(solution: &'static dyn Solution, part: Part, input: &Arc<str>, limits: &Limits) -> Row {
+    let run = runner::run_part(solution, part, input.clone(), limits);
+    let (status, answer) = match run.outcome {
+        Outcome::Solved(answer) if solution.is_stub(part) => (Status::Stub, Some(answer)),
+        Outcome::Solved(answer) => (Status::Solved, Some(answer)),
+        Outcome::Unsolved => (Status::NotSolved, None),
+        outcome => (Status::Failed(outcome), None),
+    };
+    let elapsed = (status != Status::NotSolved).then_some(run.elapsed);
+
+    Row {
+        status,
+        answer,
+        elapsed,
+    }
+}
+
+fn check(known: Option<&str>, answer: &str) -> String {
+    match known {
+        Some(known) if known == answer => "matches".to_string(),
+        Some(known) => format!("**differs**, expected {}", known),
+        None => "unknown".to_string(),
+    }
+}
+
+/// The number of parts of `day`, the last day only has one.
+fn parts(day: u8) -> usize {
+    if day == 25 {
+        1
+    } else {
+        2
+    }
+}
+
+/// Runs every day of `year` on its input in `<root>/inputs/<year>/` and renders the results.
+pub fn generate(year: &Year, root: &Path, answers: &Answers, limits: &Limits) -> String {
+    let mut table = String::from(
+        "| Day | Part | Status | Answer | Time | Known answer |\n\
+         |----:|-----:|--------|-------:|-----:|--------------|\n",
+    );
+    let mut missing = vec![];
+    let (mut solved, mut stubbed) = (0, 0);
+
+    for day in 1..=25 {
+        let Some(solution) = year.get(day) else {
+            missing.push(format!("{:02}", day));
+            writeln!(table, "| {:02} | | missing | | | |", day).unwrap();
+            continue;
+        };
+
+        let input = match input::read(&root.join(input::path(year.year, day))) {
+            Ok(input) => Arc::<str>::from(input),
+            Err(e) => {
+                writeln!(table, "| {:02} | | no input: {} | | | |", day, e).unwrap();
+                continue;
+            }
+        };
+
+        for part in Part::ALL {
+            let row = run_part(solution, part, &input, limits);
+            match row.status {
+                Status::Solved => solved += 1,
+                Status::Stub => stubbed += 1,
+                Status::NotSolved | Status::Failed(_) => {}
+            }
+
+            let (answer, check) = match &row.answer {
+                Some(answer) => (
+                    format!("`{}`", answer),
+                    check(answers.get(day, part), answer),
+                ),
+                None => (String::new(), String::new()),
+            };
+            writeln!(
+                table,
+                "| {:02} | {} | {} | {} | {} | {} |",
+                day,
+                part,
+                row.status,
+                answer,
+                row.elapsed.map(format_duration).unwrap_or_default(),
+                check
+            )
+            .unwrap();
+        }
+    }
+
+    let total: usize = year.days.iter().map(|solution| parts(solution.day())).sum();
+    let mut report = format!("# Advent of Code {}\n\n", year.year);
+    writeln!(
+        report,
+        "{} of {} parts solved, {} stubbed with a hard-coded answer.\n",
+        solved, total, stubbed
+    )
+    .unwrap();
+    if !missing.is_empty() {
+        writeln!(report, "Days without a solver: {}.\n", missing.join(", ")).unwrap();
+    }
+    report + &table
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, path::PathBuf, process};
+
+    use super::*;
+
+    /// Answers the same regardless of the input, so only the presence of the input file matters.
+    struct Fixed {
+        day: u8,
+        part_1: &'static str,
+        part_2: Option<&'static str>,
+        stub: bool,
+    }
+
+    impl Solution for Fixed {
+        fn day(&self) -> u8 {
+            self.day
+        }
+
+        fn part_1(&self, _input: &str) -> String {
+            self.part_1.to_string()
+        }
+
+        fn part_2(&self, _input: &str) -> Option<String> {
+            self.part_2.map(str::to_string)
+        }
+
+        fn is_stub(&self, _part: Part) -> bool {
+            self.stub
+        }
+    }
+
+    /// An empty directory named after the test, with inputs for `days` of 2023.
+    fn root(test: &str, days: &[u8]) -> PathBuf {
+        let root = env::temp_dir().join(format!("aoc-report-{}-{}", process::id(), test));
+        let _ = fs::remove_dir_all(&root);
+        for &day in days {
+            let path = root.join(input::path(2023, day));
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, "input\n").unwrap();
+        }
+        root
+    }
+
+    /// The cells of the row of `day` and `part` after the part, leaving out the time.
+    fn cells(report: &str, day: &str, part: &str) -> Vec<String> {
+        let prefix = format!("| {} | {} |", day, part);
+        let row = report.lines().find_map(|line| line.strip_prefix(&prefix));
+        let mut cells: Vec<_> = row
+            .unwrap()
+            .split('|')
+            .map(|cell| cell.trim().to_string())
+            .collect();
+        cells.remove(2);
+        cells
+    }
+
+    #[test]
+    fn test_generate() {
+        static DAYS: &[&dyn Solution] = &[
+            &Fixed {
+                day: 1,
+                part_1: "142",
+                part_2: Some("280"),
+                stub: false,
+            },
+            &Fixed {
+                day: 2,
+                part_1: "8",
+                part_2: None,
+                stub: true,
+            },
+        ];
+        let year = Year {
+            year: 2023,
+            days: DAYS,
+        };
+        let root = root("generate", &[1, 2]);
+        let answers = Answers::parse("01 1 142\n01 2 281\n").unwrap();
+        let report = generate(&year, &root, &answers, &Limits::default());
+        fs::remove_dir_all(root).unwrap();
+
+        assert!(report.starts_with("# Advent of Code 2023\n\n"));
+        assert!(report.contains("2 of 4 parts solved, 1 stubbed with a hard-coded answer."));
+        assert!(report.contains("Days without a solver: 03, 04, 05,"));
+
+        assert_eq!(
+            cells(&report, "01", "1"),
+            ["solved", "`142`", "matches", ""]
+        );
+        assert_eq!(
+            cells(&report, "01", "2"),
+            ["solved", "`280`", "**differs**, expected 281", ""]
+        );
+        assert_eq!(cells(&report, "02", "1"), ["stub", "`8`", "unknown", ""]);
+        assert_eq!(cells(&report, "02", "2"), ["not solved", "", "", ""]);
+    }
+
+    #[test]
+    fn test_missing_rows() {
+        static DAYS: &[&dyn Solution] = &[
+            &Fixed {
+                day: 5,
+                part_1: "35",
+                part_2: None,
+                stub: false,
+            },
+            &Fixed {
+                day: 25,
+                part_1: "54",
+                part_2: None,
+                stub: false,
+            },
+        ];
+        let year = Year {
+            year: 2023,
+            days: DAYS,
+        };
+        // Only the last day has an input.
+        let root = root("missing-rows", &[25]);
+        let report = generate(&year, &root, &Answers::default(), &Limits::default());
+        fs::remove_dir_all(root).unwrap();
+
+        // The last day has a single part.
+        assert!(report.contains("1 of 3 parts solved, 0 stubbed with a hard-coded answer."));
+        assert!(report.contains("| 04 | | missing | | | |\n"));
+        assert!(report.contains("| 05 | | no input: "));
+        assert!(!report.contains("| 05 | 1 |"));
+        assert_eq!(cells(&report, "25", "1"), ["solved", "`54`", "unknown", ""]);
+    }
+}
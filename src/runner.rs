@@ -0,0 +1,233 @@
+use std::{
+    any::Any,
+    fmt,
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        mpsc::{self, RecvTimeoutError},
+        Arc,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    alloc,
+    cancel::{self, CancellationToken, Cancelled},
+    solution::{Part, Solution},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Solved(String),
+    /// The part has no solver yet.
+    Unsolved,
+    Panicked(String),
+    TimedOut,
+    MemoryLimitExceeded,
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Solved(answer) => write!(f, "{}", answer),
+            Outcome::Unsolved => write!(f, "not solved yet"),
+            Outcome::Panicked(message) => write!(f, "panicked: {}", message),
+            Outcome::TimedOut => write!(f, "timed out"),
+            Outcome::MemoryLimitExceeded => write!(f, "exceeded the memory limit"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Limits {
+    /// Wall-clock time after which the solver gets cancelled.
+    pub time: Duration,
+    /// Maximum number of bytes the solver may have allocated at the same time. Only enforced if
+    /// the binary installed [`alloc::CountingAllocator`], which stops the solver at the allocation
+    /// that would exceed it.
+    pub memory: Option<usize>,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Self {
+            time: Duration::from_secs(10),
+            memory: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Run {
+    pub outcome: Outcome,
+    pub elapsed: Duration,
+    /// Peak number of bytes allocated by the solver, `None` without the counting allocator or if
+    /// the solver timed out or exceeded the memory limit.
+    pub peak_bytes: Option<usize>,
+}
+
+struct Finished {
+    result: thread::Result<Option<String>>,
+    elapsed: Duration,
+    peak_bytes: usize,
+}
+
+/// How often the runner looks whether the worker got stopped at its memory limit.
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Solves a single part on a worker thread, catching panics and enforcing `limits`.
+///
+/// Once the deadline passes the solver's token is cancelled and `run_part` returns right away.
+/// Solvers stop at their next [`cancel::checkpoint`], a solver without checkpoints keeps running
+/// in the background until it finishes or the process exits. A solver stopped at its memory limit
+/// is left behind the same way, blocked for good.
+pub fn run_part(
+    solution: &'static dyn Solution,
+    part: Part,
+    input: Arc<str>,
+    limits: &Limits,
+) -> Run {
+    let (sender, receiver) = mpsc::channel();
+    let token = CancellationToken::new();
+    let worker_token = token.clone();
+    let memory_limit = limits.memory;
+
+    thread::spawn(move || {
+        cancel::set_current(worker_token);
+        alloc::reset(memory_limit);
+
+        let start = Instant::now();
+        let result = panic::catch_unwind(AssertUnwindSafe(|| solution.solve(part, &input)));
+        let finished = Finished {
+            result,
+            elapsed: start.elapsed(),
+            peak_bytes: alloc::peak(),
+        };
+        // The receiver is gone if we already timed out, nobody is interested in the answer then.
+        let _ = sender.send(finished);
+    });
+
+    let start = Instant::now();
+    let finished = loop {
+        let left = limits.time.saturating_sub(start.elapsed());
+        match receiver.recv_timeout(left.min(POLL_INTERVAL)) {
+            Ok(finished) => break finished,
+            Err(RecvTimeoutError::Timeout) if token.memory_limit_exceeded() => {
+                return Run {
+                    outcome: Outcome::MemoryLimitExceeded,
+                    elapsed: start.elapsed(),
+                    peak_bytes: None,
+                };
+            }
+            Err(RecvTimeoutError::Timeout) if !left.is_zero() => {}
+            Err(_) => {
+                token.cancel();
+                return Run {
+                    outcome: Outcome::TimedOut,
+                    elapsed: limits.time,
+                    peak_bytes: None,
+                };
+            }
+        }
+    };
+
+    let outcome = match finished.result {
+        Ok(answer) => answer.map_or(Outcome::Unsolved, Outcome::Solved),
+        Err(payload) if payload.is::<Cancelled>() => Outcome::TimedOut,
+        Err(payload) => Outcome::Panicked(panic_message(payload.as_ref())),
+    };
+
+    Run {
+        outcome,
+        elapsed: finished.elapsed,
+        peak_bytes: alloc::is_installed().then_some(finished.peak_bytes),
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Endless;
+
+    impl Solution for Endless {
+        fn day(&self) -> u8 {
+            0
+        }
+
+        fn part_1(&self, _input: &str) -> String {
+            loop {
+                cancel::checkpoint();
+            }
+        }
+
+        fn part_2(&self, _input: &str) -> Option<String> {
+            let mut hoard = vec![];
+            loop {
+                cancel::checkpoint();
+                hoard.push(vec![0u8; 1024]);
+            }
+        }
+    }
+
+    /// Makes a single allocation far over any limit, without checkpoints.
+    struct Hoarder;
+
+    impl Solution for Hoarder {
+        fn day(&self) -> u8 {
+            0
+        }
+
+        fn part_1(&self, _input: &str) -> String {
+            vec![1u8; 1 << 40].len().to_string()
+        }
+
+        fn part_2(&self, _input: &str) -> Option<String> {
+            None
+        }
+    }
+
+    #[test]
+    fn test_deadline_cancels_solver() {
+        let limits = Limits {
+            time: Duration::from_millis(50),
+            memory: None,
+        };
+        let run = run_part(&Endless, Part::One, "".into(), &limits);
+
+        assert_eq!(run.outcome, Outcome::TimedOut);
+    }
+
+    #[test]
+    fn test_memory_limit() {
+        let limits = Limits {
+            time: Duration::from_secs(10),
+            memory: Some(1 << 20),
+        };
+        let run = run_part(&Endless, Part::Two, "".into(), &limits);
+
+        assert_eq!(run.outcome, Outcome::MemoryLimitExceeded);
+    }
+
+    #[test]
+    fn test_memory_limit_stops_a_single_allocation() {
+        let limits = Limits {
+            time: Duration::from_secs(10),
+            memory: Some(1 << 20),
+        };
+        let run = run_part(&Hoarder, Part::One, "".into(), &limits);
+
+        assert_eq!(run.outcome, Outcome::MemoryLimitExceeded);
+        assert!(run.elapsed < limits.time);
+    }
+}
@@ -0,0 +1,116 @@
+//! Shortest paths through implicit state spaces.
+//!
+//! The states are generated on the fly by a successor function, so searches can carry whatever
+//! they need in a state (position, heading, how long they went straight, ...) without building a
+//! graph first.
+
+use std::{cmp::Reverse, collections::BinaryHeap, collections::HashMap, hash::Hash};
+
+/// A cheapest path found by [`dijkstra`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S> {
+    pub cost: u64,
+    /// All states from the start to the goal, both included.
+    pub states: Vec<S>,
+}
+
+/// Finds the cheapest path from any of `starts` to a state for which `is_goal` holds.
+/// `successors` returns the states reachable from a state together with the cost of getting there.
+pub fn dijkstra<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    // States are stored once and referred to by index, so they don't have to be ordered to go
+    // into the heap. Each one remembers where it was reached from.
+    let mut states: Vec<(S, Option<usize>)> = vec![];
+    let mut costs: HashMap<S, (usize, u64)> = HashMap::new();
+    let mut done = vec![];
+    let mut queue = BinaryHeap::new();
+
+    for start in starts {
+        if !costs.contains_key(&start) {
+            costs.insert(start.clone(), (states.len(), 0));
+            queue.push(Reverse((0, states.len())));
+            states.push((start, None));
+            done.push(false);
+        }
+    }
+
+    while let Some(Reverse((cost, index))) = queue.pop() {
+        if done[index] {
+            continue;
+        }
+        done[index] = true;
+
+        if is_goal(&states[index].0) {
+            let mut path = vec![];
+            let mut current = Some(index);
+            while let Some(i) = current {
+                path.push(states[i].0.clone());
+                current = states[i].1;
+            }
+            path.reverse();
+            return Some(Path { cost, states: path });
+        }
+
+        for (next, step) in successors(&states[index].0) {
+            let next_cost = cost + step;
+            match costs.get_mut(&next) {
+                Some((next_index, best)) => {
+                    if next_cost < *best && !done[*next_index] {
+                        *best = next_cost;
+                        states[*next_index].1 = Some(index);
+                        queue.push(Reverse((next_cost, *next_index)));
+                    }
+                }
+                None => {
+                    costs.insert(next.clone(), (states.len(), next_cost));
+                    queue.push(Reverse((next_cost, states.len())));
+                    states.push((next, Some(index)));
+                    done.push(false);
+                }
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dijkstra() {
+        // Going 0 -> 1 -> 2 -> 3 is cheaper than the direct edges.
+        let edges = [
+            (0, 1, 1),
+            (1, 2, 1),
+            (2, 3, 1),
+            (0, 3, 5),
+            (0, 2, 3),
+            (4, 3, 0),
+        ];
+        let successors = |state: &u32| {
+            edges
+                .iter()
+                .filter(|(from, _, _)| from == state)
+                .map(|(_, to, cost)| (*to, *cost))
+                .collect::<Vec<_>>()
+        };
+
+        let path = dijkstra([0], successors, |s| *s == 3).unwrap();
+        assert_eq!(path.cost, 3);
+        assert_eq!(path.states, [0, 1, 2, 3]);
+
+        let path = dijkstra([0, 4], successors, |s| *s == 3).unwrap();
+        assert_eq!(path.states, [4, 3]);
+
+        assert_eq!(dijkstra([3], successors, |s| *s == 0), None);
+    }
+}
@@ -0,0 +1,77 @@
+use std::fmt;
+
+use crate::{explain::Trace, graph::Graph};
+
+/// A solver for a single day. Both parts get the raw puzzle input and return the answer already
+/// formatted for printing, so days with different answer types can live side by side.
+pub trait Solution: Sync {
+    /// The day of the puzzle, starting at 1.
+    fn day(&self) -> u8;
+
+    fn part_1(&self, input: &str) -> String;
+
+    /// Returns `None` as long as the second part isn't solved.
+    fn part_2(&self, input: &str) -> Option<String>;
+
+    /// Whether the answer of `part` is hard-coded instead of computed from the input.
+    fn is_stub(&self, _part: Part) -> bool {
+        false
+    }
+
+    /// Checks that `part` can be solved for `input`, returning why it can't. Inputs that pass never
+    /// make the part panic. Most days only parse here, some also search for what the part assumes
+    /// exists, like a path to the goal.
+    fn parse(&self, _part: Part, _input: &str) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// How the answer of `part` is derived from the input, for days that can explain it. The
+    /// value of the trace is the answer.
+    fn explain(&self, _part: Part, _input: &str) -> Option<Trace> {
+        None
+    }
+
+    /// The structure of the input as a graph with printable edge labels, for days whose puzzle
+    /// is about one.
+    fn graph(&self, _input: &str) -> Option<Graph<String>> {
+        None
+    }
+
+    fn solve(&self, part: Part, input: &str) -> Option<String> {
+        match part {
+            Part::One => Some(self.part_1(input)),
+            Part::Two => self.part_2(input),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn from_number(n: u8) -> Option<Part> {
+        match n {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
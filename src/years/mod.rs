@@ -0,0 +1,32 @@
+//! The solvers of every event, grouped by year.
+
+use crate::solution::Solution;
+
+pub mod y2023;
+
+pub struct Year {
+    pub year: u16,
+    /// All solved days, ordered by day.
+    pub days: &'static [&'static dyn Solution],
+}
+
+impl Year {
+    pub fn get(&self, day: u8) -> Option<&'static dyn Solution> {
+        self.days.iter().find(|s| s.day() == day).copied()
+    }
+}
+
+/// All events, ordered by year.
+pub static YEARS: &[Year] = &[Year {
+    year: 2023,
+    days: y2023::DAYS,
+}];
+
+pub fn get(year: u16) -> Option<&'static Year> {
+    YEARS.iter().find(|y| y.year == year)
+}
+
+/// The most recent event, used when no year is given.
+pub fn latest() -> &'static Year {
+    YEARS.last().expect("at least one year is registered")
+}
@@ -0,0 +1,148 @@
+use crate::{
+    explain::Trace,
+    solution::{Part, Solution},
+};
+
+const MAP: [(&str, u32); 19] = [
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+    ("1", 1),
+    ("2", 2),
+    ("3", 3),
+    ("4", 4),
+    ("5", 5),
+    ("6", 6),
+    ("7", 7),
+    ("8", 8),
+    ("9", 9),
+    ("0", 0),
+];
+
+/// A digit of a line, with the text it was read from.
+type Digit<'a> = (&'a str, u32);
+
+fn first_digit(s: &str) -> Digit<'static> {
+    let mut min_byte_index = s.len();
+    let mut number = ("", 0);
+    for (pattern, digit) in MAP {
+        let pos = s.find(pattern);
+        if let Some(pos) = pos {
+            if pos <= min_byte_index {
+                min_byte_index = pos;
+                number = (pattern, digit);
+            }
+        }
+    }
+
+    number
+}
+
+fn last_digit(s: &str) -> Digit<'static> {
+    let mut max_byte_index = 0;
+    let mut number = ("", 0);
+    for (pattern, digit) in MAP {
+        let pos = s.rfind(pattern);
+        if let Some(pos) = pos {
+            if pos >= max_byte_index {
+                max_byte_index = pos;
+                number = (pattern, digit);
+            }
+        }
+    }
+
+    number
+}
+
+/// The first and last digit of a line, ignoring spelled out digits.
+fn digits_1(line: &str) -> [Digit<'_>; 2] {
+    let first = line.find(|c: char| c.is_ascii_digit()).unwrap();
+    let last = line.rfind(|c: char| c.is_ascii_digit()).unwrap();
+    [first, last].map(|i| (&line[i..=i], (line.as_bytes()[i] - b'0') as u32))
+}
+
+fn digits_2(line: &str) -> [Digit<'_>; 2] {
+    [first_digit(line), last_digit(line)]
+}
+
+/// Every line needs a digit, which for the second part may be spelled out.
+fn parse(part: Part, input: &str) -> Result<(), String> {
+    let has_digit = |line: &str| match part {
+        Part::One => line.contains(|c: char| c.is_ascii_digit()),
+        Part::Two => MAP.iter().any(|(pattern, _)| line.contains(pattern)),
+    };
+    match input.lines().find(|line| !has_digit(line)) {
+        Some(line) => Err(format!("no digit in '{}'", line)),
+        None => Ok(()),
+    }
+}
+
+fn calibration_value([(_, first), (_, last)]: [Digit; 2]) -> u32 {
+    first * 10 + last
+}
+
+fn part_1(input: &str) -> u32 {
+    input
+        .lines()
+        .map(|line| calibration_value(digits_1(line)))
+        .sum()
+}
+
+fn part_2(input: &str) -> u32 {
+    input
+        .lines()
+        .map(|line| calibration_value(digits_2(line)))
+        .sum()
+}
+
+/// Every line with its calibration value and the digits it was made of.
+fn explain(input: &str, digits: fn(&str) -> [Digit; 2]) -> Trace {
+    let mut sum = 0;
+    let mut lines = vec![];
+    for line in input.lines() {
+        let digits = digits(line);
+        let value = calibration_value(digits);
+        sum += value;
+        lines.push(
+            Trace::new(line)
+                .value(value)
+                .steps(digits.map(|(text, digit)| Trace::new(format!("'{}'", text)).value(digit))),
+        );
+    }
+    Trace::new("sum of the calibration values")
+        .value(sum)
+        .steps(lines)
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    fn day(&self) -> u8 {
+        1
+    }
+
+    fn part_1(&self, input: &str) -> String {
+        part_1(input).to_string()
+    }
+
+    fn part_2(&self, input: &str) -> Option<String> {
+        Some(part_2(input).to_string())
+    }
+
+    fn parse(&self, part: Part, input: &str) -> Result<(), String> {
+        parse(part, input)
+    }
+
+    fn explain(&self, part: Part, input: &str) -> Option<Trace> {
+        match part {
+            Part::One => Some(explain(input, digits_1)),
+            Part::Two => Some(explain(input, digits_2)),
+        }
+    }
+}
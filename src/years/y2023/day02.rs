@@ -0,0 +1,226 @@
+use std::{fmt, str::FromStr};
+
+use crate::{
+    explain::Trace,
+    input,
+    solution::{Part, Solution},
+};
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+impl FromStr for Color {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "red" => Result::Ok(Color::Red),
+            "green" => Result::Ok(Color::Green),
+            "blue" => Result::Ok(Color::Blue),
+            _ => Err(format!("expected red, green or blue, but got '{}'", input)),
+        }
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Color::Red => write!(f, "red"),
+            Color::Green => write!(f, "green"),
+            Color::Blue => write!(f, "blue"),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Draw(Color, u32);
+
+impl FromStr for Draw {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (count, color) = input::split_once(input.trim(), " ")?;
+
+        let count = input::number(count)?;
+        let color = color.parse()?;
+
+        Result::Ok(Draw(color, count))
+    }
+}
+
+#[derive(Debug)]
+struct Game {
+    id: u32,
+    draws: Vec<Vec<Draw>>,
+}
+impl Game {
+    fn is_possible(&self, bag: &[Draw]) -> bool {
+        self.too_many(bag).is_none()
+    }
+
+    /// The first cubes drawn that the bag doesn't have enough of, and what the bag has instead.
+    fn too_many<'a>(&'a self, bag: &'a [Draw]) -> Option<(&'a Draw, &'a Draw)> {
+        for draw in self.draws.iter() {
+            for color in draw {
+                for bag_color in bag {
+                    if bag_color.0 == color.0 && color.1 > bag_color.1 {
+                        return Some((color, bag_color));
+                    }
+                }
+            }
+        }
+
+        None
+    }
+
+    fn min_bag(&self) -> Vec<Draw> {
+        let reds: u32 = self
+            .draws
+            .iter()
+            .filter_map(|b| b.iter().find(|d| d.0 == Color::Red))
+            .map(|d| d.1)
+            .max()
+            .unwrap_or(0);
+
+        let greens: u32 = self
+            .draws
+            .iter()
+            .filter_map(|b| b.iter().find(|d| d.0 == Color::Green))
+            .map(|d| d.1)
+            .max()
+            .unwrap_or(0);
+
+        let blues: u32 = self
+            .draws
+            .iter()
+            .filter_map(|b| b.iter().find(|d| d.0 == Color::Blue))
+            .map(|d| d.1)
+            .max()
+            .unwrap_or(0);
+
+        vec![
+            Draw(Color::Red, reds),
+            Draw(Color::Blue, blues),
+            Draw(Color::Green, greens),
+        ]
+    }
+}
+
+fn parse_line(line: &str) -> Result<Game, String> {
+    let (game, draws_s) = input::split_once(line, ":")?;
+    let (_game, id) = input::split_once(game, " ")?;
+
+    let mut draws = vec![];
+    for draw in draws_s.split(';') {
+        let hand = draw
+            .split(',')
+            .map(|c| c.parse())
+            .collect::<Result<_, _>>()?;
+        draws.push(hand);
+    }
+
+    Ok(Game {
+        id: input::number(id)?,
+        draws,
+    })
+}
+
+fn parse(input: &str) -> Result<Vec<Game>, String> {
+    input.lines().map(parse_line).collect()
+}
+
+fn bag() -> Vec<Draw> {
+    vec![
+        Draw(Color::Red, 12),
+        Draw(Color::Green, 13),
+        Draw(Color::Blue, 14),
+    ]
+}
+
+fn part_1(games: &[Game]) -> u32 {
+    let bag = bag();
+
+    games
+        .iter()
+        .filter(|game| game.is_possible(&bag))
+        .map(|game| game.id)
+        .sum()
+}
+
+fn power(bag: &[Draw]) -> u32 {
+    bag.iter().map(|d| d.1).product()
+}
+
+fn part_2(games: &[Game]) -> u32 {
+    let mut sum_power: u32 = 0;
+    for game in games {
+        sum_power += power(&game.min_bag());
+    }
+    sum_power
+}
+
+/// Every game with whether it was possible, and if not which draw gave it away.
+fn explain_1(games: &[Game]) -> Trace {
+    let bag = bag();
+    let steps = games.iter().map(|game| {
+        let step = Trace::new(format!("Game {}", game.id));
+        match game.too_many(&bag) {
+            None => step.value("possible"),
+            Some((draw, bag)) => step.value(format!(
+                "impossible, drew {} {} but the bag has {}",
+                draw.1, draw.0, bag.1
+            )),
+        }
+    });
+    Trace::new("sum of the ids of the possible games")
+        .value(part_1(games))
+        .steps(steps)
+}
+
+/// Every game with the fewest cubes it needs and their power.
+fn explain_2(games: &[Game]) -> Trace {
+    let steps = games.iter().map(|game| {
+        let bag = game.min_bag();
+        Trace::new(format!("Game {}", game.id))
+            .value(power(&bag))
+            .steps(
+                bag.iter()
+                    .map(|draw| Trace::new(draw.0.to_string()).value(draw.1)),
+            )
+    });
+    Trace::new("sum of the powers of the fewest cubes per game")
+        .value(part_2(games))
+        .steps(steps)
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    fn day(&self) -> u8 {
+        2
+    }
+
+    fn part_1(&self, input: &str) -> String {
+        part_1(&parse(input).unwrap()).to_string()
+    }
+
+    fn part_2(&self, input: &str) -> Option<String> {
+        Some(part_2(&parse(input).unwrap()).to_string())
+    }
+
+    fn parse(&self, _part: Part, input: &str) -> Result<(), String> {
+        parse(input).map(|_| ())
+    }
+
+    fn explain(&self, part: Part, input: &str) -> Option<Trace> {
+        let games = parse(input).unwrap();
+        match part {
+            Part::One => Some(explain_1(&games)),
+            Part::Two => Some(explain_2(&games)),
+        }
+    }
+}
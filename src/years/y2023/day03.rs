@@ -1,5 +1,10 @@
 use itertools::Itertools;
-use std::{fs::read_to_string, ops::RangeInclusive};
+use std::ops::RangeInclusive;
+
+use crate::{
+    input,
+    solution::{Part, Solution},
+};
 
 #[derive(Debug)]
 struct Grid {
@@ -24,7 +29,8 @@ impl Grid {
         result
     }
 
-    fn gear_ratios(&self) -> Vec<u32> {
+    /// The products of the two numbers next to each gear, which always fit in an `u64`.
+    fn gear_ratios(&self) -> Vec<u64> {
         let mut result = vec![];
         for (symbol, x, y) in self.symbols.iter() {
             if *symbol == '*' {
@@ -33,7 +39,7 @@ impl Grid {
                     let rect_x = *x_range.start() as i32 - 1..=*x_range.end() as i32 + 1;
                     let rect_y = *y_pos as i32 - 1..=*y_pos as i32 + 1;
                     if rect_x.contains(&(*x as i32)) && rect_y.contains(&(*y as i32)) {
-                        adjacent.push(*number);
+                        adjacent.push(*number as u64);
                     }
                 }
 
@@ -47,7 +53,7 @@ impl Grid {
     }
 }
 
-fn parse_input(input: &str) -> Grid {
+fn parse_input(input: &str) -> Result<Grid, String> {
     let mut grid = Grid {
         numbers: vec![],
         symbols: vec![],
@@ -62,13 +68,8 @@ fn parse_input(input: &str) -> Grid {
             match key {
                 1 => {
                     let group = group.collect::<Vec<_>>();
-                    let number: u32 = group
-                        .iter()
-                        .map(|(_x, d)| d.to_digit(10).unwrap())
-                        .rev()
-                        .enumerate()
-                        .map(|(i, d)| d * 10_u32.pow(i as u32))
-                        .sum();
+                    let digits = group.iter().map(|(_x, d)| d).collect::<String>();
+                    let number: u32 = input::number(&digits)?;
 
                     let range = group.first().unwrap().0..=group.last().unwrap().0;
                     grid.numbers.push((number, range, y));
@@ -84,15 +85,38 @@ fn parse_input(input: &str) -> Grid {
         }
     }
 
-    grid
+    Ok(grid)
 }
 
-fn main() {
-    let input = read_to_string("inputs/day03.txt").expect("file not found");
+pub struct Day03;
+
+impl Solution for Day03 {
+    fn day(&self) -> u8 {
+        3
+    }
 
-    let grid = parse_input(&input);
+    fn part_1(&self, input: &str) -> String {
+        let grid = parse_input(input).unwrap();
+        grid.part_numbers()
+            .iter()
+            .map(|n| *n as u64)
+            .sum::<u64>()
+            .to_string()
+    }
 
-    println!("Part 1: {}", grid.part_numbers().iter().sum::<u32>());
+    fn part_2(&self, input: &str) -> Option<String> {
+        let grid = parse_input(input).unwrap();
+        // Unlike every ratio on its own, their sum may need more than 64 bits.
+        Some(
+            grid.gear_ratios()
+                .iter()
+                .map(|r| *r as u128)
+                .sum::<u128>()
+                .to_string(),
+        )
+    }
 
-    println!("Part 2: {}", grid.gear_ratios().iter().sum::<u32>());
+    fn parse(&self, _part: Part, input: &str) -> Result<(), String> {
+        parse_input(input).map(|_| ())
+    }
 }
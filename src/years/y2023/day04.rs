@@ -1,14 +1,16 @@
-use std::{
-    collections::{HashMap, VecDeque},
-    fs::read_to_string,
+use std::collections::{HashMap, VecDeque};
+
+use crate::{
+    input,
+    solution::{Part, Solution},
 };
 
-fn parse_numbers(input: &str) -> Vec<u32> {
+fn parse_numbers(input: &str) -> Result<Vec<u32>, String> {
     input
         .trim()
         .split(" ")
         .filter(|s| !s.is_empty())
-        .map(|s| s.trim().parse::<u32>().unwrap())
+        .map(|s| input::number(s.trim()))
         .collect()
 }
 
@@ -51,21 +53,21 @@ struct Puzzle {
     cards: Vec<Card>,
 }
 
-fn parse_input(input: &str) -> Puzzle {
+fn parse_input(input: &str) -> Result<Puzzle, String> {
     let mut cards = vec![];
 
     for (index, line) in input.lines().enumerate() {
-        let (_, numbers) = line.split_once(":").unwrap();
+        let (_, numbers) = input::split_once(line, ":")?;
 
-        let (winning, own) = numbers.split_once("|").unwrap();
+        let (winning, own) = input::split_once(numbers, "|")?;
         cards.push(Card {
             index,
-            winning: parse_numbers(winning),
-            own: parse_numbers(own),
+            winning: parse_numbers(winning)?,
+            own: parse_numbers(own)?,
         });
     }
 
-    Puzzle { cards }
+    Ok(Puzzle { cards })
 }
 
 fn part_2(game: &Puzzle) -> u32 {
@@ -98,15 +100,23 @@ fn part_2(game: &Puzzle) -> u32 {
     result
 }
 
-fn main() {
-    let input = read_to_string("inputs/day04.txt").expect("file not found");
+pub struct Day04;
+
+impl Solution for Day04 {
+    fn day(&self) -> u8 {
+        4
+    }
 
-    let game = parse_input(&input);
+    fn part_1(&self, input: &str) -> String {
+        let game = parse_input(input).unwrap();
+        game.cards.iter().map(|c| c.points()).sum::<u32>().to_string()
+    }
 
-    println!(
-        "Part 1: {}",
-        game.cards.iter().map(|c| c.points()).sum::<u32>()
-    );
+    fn part_2(&self, input: &str) -> Option<String> {
+        Some(part_2(&parse_input(input).unwrap()).to_string())
+    }
 
-    println!("Part 2: {}", part_2(&game));
+    fn parse(&self, _part: Part, input: &str) -> Result<(), String> {
+        parse_input(input).map(|_| ())
+    }
 }
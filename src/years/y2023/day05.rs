@@ -0,0 +1,271 @@
+use std::{ops::Range, str::FromStr};
+
+use crate::{
+    input,
+    solution::{Part, Solution},
+};
+
+/// One line of a map: `length` numbers starting at `source` map to the ones starting at
+/// `destination`.
+#[derive(Debug)]
+struct MapRange {
+    destination: u64,
+    source: u64,
+    length: u64,
+}
+
+impl MapRange {
+    fn source_range(&self) -> Range<u64> {
+        self.source..self.source + self.length
+    }
+
+    fn apply(&self, n: u64) -> u64 {
+        n - self.source + self.destination
+    }
+}
+
+impl FromStr for MapRange {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let numbers = parse_numbers(input)?;
+        let [destination, source, length] = numbers[..] else {
+            return Err(format!("expected three numbers, got '{}'", input));
+        };
+        if destination.checked_add(length).is_none() || source.checked_add(length).is_none() {
+            return Err(format!("range too large in '{}'", input));
+        }
+
+        Ok(MapRange {
+            destination,
+            source,
+            length,
+        })
+    }
+}
+
+/// An `x-to-y map:` block. Numbers not covered by any of its ranges map to themselves.
+#[derive(Debug)]
+struct Map {
+    /// Sorted by source and not overlapping.
+    ranges: Vec<MapRange>,
+}
+
+impl Map {
+    fn apply(&self, n: u64) -> u64 {
+        self.ranges
+            .iter()
+            .find(|r| r.source_range().contains(&n))
+            .map_or(n, |r| r.apply(n))
+    }
+
+    /// Maps a whole range of numbers at once. The range is split wherever it crosses the border of
+    /// a map range, so the result has at most two pieces more than there are map ranges.
+    fn apply_range(&self, range: Range<u64>) -> Vec<Range<u64>> {
+        let mut result = vec![];
+        let mut start = range.start;
+
+        for map_range in self.ranges.iter() {
+            if start >= range.end {
+                break;
+            }
+            let source = map_range.source_range();
+            if source.end <= start {
+                continue;
+            }
+
+            // The part before this map range isn't mapped.
+            if start < source.start {
+                let end = source.start.min(range.end);
+                result.push(start..end);
+                start = end;
+            }
+
+            let end = source.end.min(range.end);
+            if start < end {
+                result.push(map_range.apply(start)..map_range.apply(end - 1) + 1);
+                start = end;
+            }
+        }
+
+        if start < range.end {
+            result.push(start..range.end);
+        }
+        result
+    }
+}
+
+impl FromStr for Map {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut lines = input::lines(input);
+        let header = lines.next().unwrap_or_default();
+        if !header.ends_with(" map:") {
+            return Err(format!("expected a map header, got '{}'", header));
+        }
+
+        let mut ranges = lines
+            .map(|line| line.parse())
+            .collect::<Result<Vec<MapRange>, _>>()?;
+        ranges.sort_by_key(|r| r.source);
+        if let Some(pair) = ranges
+            .windows(2)
+            .find(|pair| pair[0].source_range().end > pair[1].source)
+        {
+            return Err(format!(
+                "the ranges starting at {} and {} overlap",
+                pair[0].source, pair[1].source
+            ));
+        }
+
+        Ok(Map { ranges })
+    }
+}
+
+#[derive(Debug)]
+struct Puzzle {
+    seeds: Vec<u64>,
+    /// The maps in the order they have to be applied, from seed to location.
+    maps: Vec<Map>,
+}
+
+impl Puzzle {
+    fn location(&self, seed: u64) -> u64 {
+        self.maps.iter().fold(seed, |n, map| map.apply(n))
+    }
+
+    /// The seeds interpreted as pairs of range start and length.
+    fn seed_ranges(&self) -> Vec<Range<u64>> {
+        self.seeds
+            .chunks(2)
+            .map(|pair| pair[0]..pair[0] + pair[1])
+            .collect()
+    }
+}
+
+fn parse_numbers(input: &str) -> Result<Vec<u64>, String> {
+    input
+        .split(' ')
+        .filter(|s| !s.is_empty())
+        .map(input::number)
+        .collect()
+}
+
+impl FromStr for Puzzle {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut blocks = input::blocks(input);
+        let seeds = blocks
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .strip_prefix("seeds: ")
+            .ok_or("no seeds")?;
+        let seeds = parse_numbers(seeds)?;
+        if seeds.is_empty() {
+            return Err("no seeds".to_string());
+        }
+        let maps = blocks
+            .map(|block| block.parse())
+            .collect::<Result<_, _>>()?;
+
+        Ok(Puzzle { seeds, maps })
+    }
+}
+
+/// Parses the puzzle, for the second part making sure the seeds are pairs of range start and
+/// length with at least one seed in each range.
+fn parse(part: Part, input: &str) -> Result<Puzzle, String> {
+    let puzzle: Puzzle = input.parse()?;
+    if part == Part::Two {
+        if puzzle.seeds.len() % 2 == 1 {
+            return Err("expected pairs of seed range starts and lengths".to_string());
+        }
+        for pair in puzzle.seeds.chunks(2) {
+            if pair[1] == 0 || pair[0].checked_add(pair[1]).is_none() {
+                return Err(format!("invalid seed range {} {}", pair[0], pair[1]));
+            }
+        }
+    }
+    Ok(puzzle)
+}
+
+fn part_1(puzzle: &Puzzle) -> u64 {
+    puzzle
+        .seeds
+        .iter()
+        .map(|seed| puzzle.location(*seed))
+        .min()
+        .expect("no seeds")
+}
+
+fn part_2(puzzle: &Puzzle) -> u64 {
+    // Instead of looking at billions of seeds the ranges are pushed through the maps as a whole,
+    // each map splitting them into at most a handful of pieces.
+    let locations = puzzle
+        .maps
+        .iter()
+        .fold(puzzle.seed_ranges(), |ranges, map| {
+            ranges
+                .into_iter()
+                .flat_map(|range| map.apply_range(range))
+                .collect()
+        });
+
+    locations
+        .iter()
+        .map(|range| range.start)
+        .min()
+        .expect("no seeds")
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    fn day(&self) -> u8 {
+        5
+    }
+
+    fn part_1(&self, input: &str) -> String {
+        part_1(&parse(Part::One, input).unwrap()).to_string()
+    }
+
+    fn part_2(&self, input: &str) -> Option<String> {
+        Some(part_2(&parse(Part::Two, input).unwrap()).to_string())
+    }
+
+    fn parse(&self, part: Part, input: &str) -> Result<(), String> {
+        parse(part, input).map(|_| ())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answers::assert_example;
+
+    const EXAMPLE: &str = include_str!("../../../fixtures/2023/day05/example.txt");
+    const ANSWERS: &str = include_str!("../../../fixtures/2023/day05/answers.txt");
+
+    #[test]
+    fn test_example() {
+        assert_example(&Day05, EXAMPLE, ANSWERS);
+    }
+
+    #[test]
+    fn test_apply_range_matches_single_seeds() {
+        let puzzle: Puzzle = EXAMPLE.parse().unwrap();
+        for map in puzzle.maps.iter() {
+            let mapped = map.apply_range(0..110);
+            assert_eq!(mapped.iter().map(|r| r.end - r.start).sum::<u64>(), 110);
+
+            let mut expected = (0..110).map(|n| map.apply(n)).collect::<Vec<_>>();
+            let mut actual = mapped.into_iter().flatten().collect::<Vec<_>>();
+            expected.sort();
+            actual.sort();
+            assert_eq!(actual, expected);
+        }
+    }
+}
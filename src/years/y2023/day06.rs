@@ -1,4 +1,7 @@
-use std::fs::read_to_string;
+use crate::{
+    input,
+    solution::{Part, Solution},
+};
 
 /*
  * The following holds for D = distance, R = record (distance), x = acceleration time, y = driving
@@ -33,11 +36,11 @@ fn accelerations_for_record(Race { duration, record }: &Race) -> (f64, f64) {
     (x_1, x_2)
 }
 
-fn parse_numbers(input: &str) -> Vec<i64> {
+fn parse_numbers(input: &str) -> Result<Vec<i64>, String> {
     input
         .split(' ')
         .filter(|s| !s.is_empty())
-        .map(|s| s.parse().unwrap())
+        .map(input::number)
         .collect()
 }
 
@@ -58,7 +61,7 @@ fn part_1_and_2(races: &[Race]) -> i64 {
 ///
 ///   Time:   1232100
 ///   Record: 13422132231
-fn unkern(races: &[Race]) -> Race {
+fn unkern(races: &[Race]) -> Result<Race, String> {
     let (durations, records): (Vec<String>, Vec<String>) = races
         .iter()
         .map(|Race { duration, record }| (duration.to_string(), record.to_string()))
@@ -67,33 +70,76 @@ fn unkern(races: &[Race]) -> Race {
     let duration: String = durations.into_iter().collect();
     let record: String = records.into_iter().collect();
 
-    Race {
-        duration: duration.parse().unwrap(),
-        record: record.parse().unwrap(),
-    }
+    Ok(Race {
+        duration: input::number(&duration)?,
+        record: input::number(&record)?,
+    })
 }
 
-fn main() {
-    let input = read_to_string("inputs/day06.txt").expect("file not found");
-
+fn parse(input: &str) -> Result<Vec<Race>, String> {
     let mut lines = input.lines();
 
-    let (_, time) = lines.next().unwrap().split_once(": ").unwrap();
-    let (_, record_distance) = lines.next().unwrap().split_once(": ").unwrap();
+    let (_, time) = input::split_once(lines.next().ok_or("no times")?, ": ")?;
+    let (_, record_distance) = input::split_once(lines.next().ok_or("no distances")?, ": ")?;
 
-    let durations = parse_numbers(time);
-    let record_distances = parse_numbers(record_distance);
+    let durations = parse_numbers(time)?;
+    let record_distances = parse_numbers(record_distance)?;
 
-    let races = durations
+    if durations.is_empty() || durations.len() != record_distances.len() {
+        return Err("expected as many times as distances".to_string());
+    }
+    if durations
+        .iter()
+        .chain(record_distances.iter())
+        .any(|n| *n < 0)
+    {
+        return Err("times and distances can't be negative".to_string());
+    }
+
+    Ok(durations
         .iter()
         .zip(record_distances.iter())
         .map(|(duration, record)| Race {
             duration: *duration,
             record: *record,
         })
-        .collect::<Vec<_>>();
+        .collect())
+}
 
-    println!("Part 1: {}", part_1_and_2(&races));
+/// The races of `part`, for the second part the single one hidden by the bad kerning.
+fn parse_part(part: Part, input: &str) -> Result<Vec<Race>, String> {
+    let races = parse(input)?;
+    match part {
+        Part::One => {
+            // A race can't be won in more ways than it lasts, which bounds the product.
+            races
+                .iter()
+                .try_fold(1i64, |product, race| {
+                    product.checked_mul(race.duration.max(1))
+                })
+                .ok_or("races too long")?;
+            Ok(races)
+        }
+        Part::Two => Ok(vec![unkern(&races)?]),
+    }
+}
 
-    println!("Part 2: {}", part_1_and_2(&[unkern(&races)]));
+pub struct Day06;
+
+impl Solution for Day06 {
+    fn day(&self) -> u8 {
+        6
+    }
+
+    fn part_1(&self, input: &str) -> String {
+        part_1_and_2(&parse_part(Part::One, input).unwrap()).to_string()
+    }
+
+    fn part_2(&self, input: &str) -> Option<String> {
+        Some(part_1_and_2(&parse_part(Part::Two, input).unwrap()).to_string())
+    }
+
+    fn parse(&self, part: Part, input: &str) -> Result<(), String> {
+        parse_part(part, input).map(|_| ())
+    }
 }
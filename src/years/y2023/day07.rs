@@ -1,5 +1,11 @@
 use itertools::Itertools;
-use std::{collections::HashMap, fs::read_to_string};
+use std::collections::HashMap;
+
+use crate::{
+    explain::Trace,
+    input,
+    solution::{Part, Solution},
+};
 
 #[derive(Debug, Hash, Clone, Copy, Eq, PartialEq)]
 struct Card(char);
@@ -45,7 +51,8 @@ impl PartialOrd for Hand {
 }
 
 fn type_value(unique_cards: &[(Card, usize)]) -> u32 {
-    let type_value = if unique_cards.len() == 1 {
+    
+    if unique_cards.len() == 1 {
         7_000_000
     } else if unique_cards[0].1 == 4 {
         6_000_000
@@ -59,14 +66,30 @@ fn type_value(unique_cards: &[(Card, usize)]) -> u32 {
         2_000_000
     } else {
         1_000_000
-    };
-    type_value
+    }
+}
+
+/// The name of the type a hand value was scored as, see `type_value`.
+fn type_name(value: u32) -> &'static str {
+    match value / 1_000_000 {
+        7 => "five of a kind",
+        6 => "four of a kind",
+        5 => "full house",
+        4 => "three of a kind",
+        3 => "two pair",
+        2 => "one pair",
+        _ => "high card",
+    }
 }
 
 impl Hand {
+    fn cards(&self) -> String {
+        self.0.iter().map(|card| card.0).collect()
+    }
+
     fn value(&self) -> u32 {
         let mut unique_cards = self.unique_cards().into_iter().collect::<Vec<_>>();
-        unique_cards.sort_by(|a, b| b.1.cmp(&a.1));
+        unique_cards.sort_by_key(|c| std::cmp::Reverse(c.1));
 
         let type_value = type_value(&unique_cards);
         let hand_value = self.pure_hand_value(Card::value);
@@ -85,7 +108,7 @@ impl Hand {
 
     fn value_with_joker(&self) -> u32 {
         let mut unique_cards = self.unique_cards().into_iter().collect::<Vec<_>>();
-        unique_cards.sort_by(|a, b| b.1.cmp(&a.1));
+        unique_cards.sort_by_key(|c| std::cmp::Reverse(c.1));
         let joker_count = unique_cards
             .iter()
             .find(|(c, _)| c.is_joker())
@@ -131,7 +154,7 @@ struct Puzzle {
 }
 
 fn total_winnings(bids: &[Bid]) -> u32 {
-    bids.into_iter()
+    bids.iter()
         .enumerate()
         .map(|(i, bid)| bid.bid * (i as u32 + 1))
         .sum()
@@ -148,29 +171,76 @@ fn part_2(puzzle: &Puzzle) -> u32 {
     let mut puzzle = puzzle.clone();
     puzzle
         .bids
-        .sort_by(|a, b| a.hand.value_with_joker().cmp(&b.hand.value_with_joker()));
+        .sort_by_key(|a| a.hand.value_with_joker());
 
     total_winnings(&puzzle.bids)
 }
 
-fn main() {
-    let input = read_to_string("inputs/day07.txt").expect("file not found");
+/// Every hand from the weakest to the strongest, with the type it was assigned and what it won.
+fn explain(puzzle: &Puzzle, value: fn(&Hand) -> u32) -> Trace {
+    let mut bids = puzzle.bids.clone();
+    bids.sort_by_key(|bid| value(&bid.hand));
+
+    let steps = bids.iter().enumerate().map(|(i, bid)| {
+        let rank = i as u32 + 1;
+        Trace::new(bid.hand.cards())
+            .value(bid.bid * rank)
+            .step(Trace::new("type").value(type_name(value(&bid.hand))))
+            .step(Trace::new("rank").value(rank))
+            .step(Trace::new("bid").value(bid.bid))
+    });
+    Trace::new("total winnings")
+        .value(total_winnings(&bids))
+        .steps(steps)
+}
 
+fn parse(input: &str) -> Result<Puzzle, String> {
     let mut bids = vec![];
     for line in input.lines() {
-        let (hand, bid) = line.split_once(" ").unwrap();
-        let bid = bid.parse().unwrap();
-        let hand = Hand(
-            hand.chars()
-                .map(|c| Card(c))
-                .collect::<Vec<_>>()
-                .try_into()
-                .unwrap(),
-        );
-        bids.push(Bid { bid, hand });
-    }
-    let puzzle = Puzzle { bids };
-
-    println!("Part 1: {}", part_1(&puzzle));
-    println!("Part 2: {}", part_2(&puzzle));
+        let (hand, bid) = input::split_once(line, " ")?;
+        let bid = input::number(bid)?;
+        let cards = hand
+            .chars()
+            .map(|c| match c {
+                '2'..='9' | 'T' | 'J' | 'Q' | 'K' | 'A' => Ok(Card(c)),
+                _ => Err(format!("unknown card '{}'", c)),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let cards = cards
+            .try_into()
+            .map_err(|_| format!("expected five cards, got '{}'", hand))?;
+        bids.push(Bid {
+            bid,
+            hand: Hand(cards),
+        });
+    }
+    Ok(Puzzle { bids })
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    fn day(&self) -> u8 {
+        7
+    }
+
+    fn part_1(&self, input: &str) -> String {
+        part_1(&parse(input).unwrap()).to_string()
+    }
+
+    fn part_2(&self, input: &str) -> Option<String> {
+        Some(part_2(&parse(input).unwrap()).to_string())
+    }
+
+    fn parse(&self, _part: Part, input: &str) -> Result<(), String> {
+        parse(input).map(|_| ())
+    }
+
+    fn explain(&self, part: Part, input: &str) -> Option<Trace> {
+        let puzzle = parse(input).unwrap();
+        match part {
+            Part::One => Some(explain(&puzzle, Hand::value)),
+            Part::Two => Some(explain(&puzzle, Hand::value_with_joker)),
+        }
+    }
 }
@@ -0,0 +1,224 @@
+use std::{fmt, str::FromStr};
+
+use crate::{
+    cancel,
+    graph::{Graph, NodeId},
+    input, profile,
+    solution::{Part, Solution},
+};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Direction {
+    Left,
+    Right,
+}
+
+impl FromStr for Direction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "L" => Ok(Self::Left),
+            "R" => Ok(Self::Right),
+            _ => Err(format!("expected L or R, got '{}'", s)),
+        }
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Left => write!(f, "L"),
+            Self::Right => write!(f, "R"),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Puzzle {
+    directions: Vec<Direction>,
+    /// Every node has an edge to the left and one to the right.
+    network: Graph<Direction>,
+}
+
+fn parse_mapping(input: &str) -> Result<(String, (String, String)), String> {
+    let (from, to) = input::split_once(input, " = ")?;
+
+    let to = to
+        .strip_prefix('(')
+        .and_then(|to| to.strip_suffix(')'))
+        .ok_or_else(|| format!("expected parentheses around '{}'", to))?;
+    let (to_left, to_right) = input::split_once(to, ", ")?;
+
+    Ok((from.to_owned(), (to_left.to_owned(), to_right.to_owned())))
+}
+
+fn parse_puzzle(input: &str) -> Result<Puzzle, String> {
+    let _phase = profile::phase("parse");
+    let mut blocks = input::blocks(input);
+    let directions = blocks.next().ok_or("no directions")?;
+    let mappings = blocks.next().ok_or("no network")?;
+
+    let directions = directions
+        .chars()
+        .map(|c| c.to_string().parse::<Direction>())
+        .collect::<Result<_, _>>()?;
+
+    let mut network = Graph::new();
+    for line in input::lines(mappings) {
+        let (from, (left, right)) = parse_mapping(line)?;
+        network.add_edge(&from, &left, Direction::Left);
+        network.add_edge(&from, &right, Direction::Right);
+    }
+    if let Some(node) = network
+        .nodes()
+        .find(|&node| network.successors(node).next().is_none())
+    {
+        return Err(format!("no mapping for node {}", network.label(node)));
+    }
+
+    Ok(Puzzle {
+        directions,
+        network,
+    })
+}
+
+fn advance(puzzle: &Puzzle, current_pos: NodeId, direction: Direction) -> NodeId {
+    puzzle
+        .network
+        .successors(current_pos)
+        .find(|edge| edge.label == direction)
+        .unwrap()
+        .to
+}
+
+fn step_count(start_node: NodeId, puzzle: &Puzzle, is_end_node: fn(&str) -> bool) -> u64 {
+    let _phase = profile::phase("step_count");
+    puzzle
+        .directions
+        .iter()
+        .cycle()
+        .scan(start_node, |state, dir| {
+            cancel::checkpoint();
+            *state = advance(puzzle, *state, *dir);
+            if is_end_node(puzzle.network.label(*state)) {
+                None
+            } else {
+                Some("foo")
+            }
+        })
+        .count() as u64
+        + 1
+}
+
+fn part_1(puzzle: &Puzzle) -> u64 {
+    let start = puzzle.network.id("AAA").expect("no node AAA");
+    step_count(start, puzzle, |node| node == "ZZZ")
+}
+
+fn start_nodes(puzzle: &Puzzle) -> impl Iterator<Item = NodeId> + '_ {
+    puzzle
+        .network
+        .nodes()
+        .filter(|node| puzzle.network.label(*node).ends_with('A'))
+}
+
+fn parse(part: Part, input: &str) -> Result<Puzzle, String> {
+    let puzzle = parse_puzzle(input)?;
+    match part {
+        Part::One if puzzle.network.id("AAA").is_none() => Err("no node AAA".to_string()),
+        Part::Two if start_nodes(&puzzle).next().is_none() => {
+            Err("no node ending with A".to_string())
+        }
+        _ => Ok(puzzle),
+    }
+}
+
+/// The least common multiple of the step counts can outgrow them by far, so it's a `u128`.
+fn part_2(puzzle: &Puzzle) -> u128 {
+    // The problem "execute all steps in parallel and stop if all parallel paths reach an end" can
+    // be reformulated in terms of the least common multiple of each step count.
+    start_nodes(puzzle)
+        .map(|start_node| step_count(start_node, puzzle, |node| node.ends_with('Z')) as u128)
+        .reduce(least_common_multiple)
+        .expect("no start nodes found")
+}
+
+/// See https://en.wikipedia.org/wiki/Greatest_common_divisor#Euclidean_algorithm
+fn greatest_common_divisor(a: u128, b: u128) -> u128 {
+    let mut a = a;
+    let mut b = b;
+
+    while b != 0 {
+        (a, b) = (b, a.rem_euclid(b));
+    }
+
+    a
+}
+
+/// See https://en.wikipedia.org/wiki/Least_common_multiple#Using_the_greatest_common_divisor
+fn least_common_multiple(a: u128, b: u128) -> u128 {
+    if a == 0 && b == 0 {
+        return 0;
+    }
+    a * (b / greatest_common_divisor(a, b))
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    fn day(&self) -> u8 {
+        8
+    }
+
+    fn part_1(&self, input: &str) -> String {
+        part_1(&parse(Part::One, input).unwrap()).to_string()
+    }
+
+    fn part_2(&self, input: &str) -> Option<String> {
+        Some(part_2(&parse(Part::Two, input).unwrap()).to_string())
+    }
+
+    fn parse(&self, part: Part, input: &str) -> Result<(), String> {
+        parse(part, input).map(|_| ())
+    }
+
+    fn graph(&self, input: &str) -> Option<Graph<String>> {
+        let network = parse_puzzle(input).unwrap().network;
+        Some(network.map_edges(|d| d.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd() {
+        assert_eq!(greatest_common_divisor(48, 18), 6);
+        assert_eq!(greatest_common_divisor(1, 0), 1);
+        assert_eq!(greatest_common_divisor(0, 1), 1);
+        assert_eq!(greatest_common_divisor(0, 0), 0);
+    }
+
+    #[test]
+    fn test_lcm() {
+        assert_eq!(least_common_multiple(21, 6), 42);
+        assert_eq!(least_common_multiple(1, 0), 0);
+        assert_eq!(least_common_multiple(0, 1), 0);
+        assert_eq!(least_common_multiple(0, 0), 0);
+    }
+
+    #[test]
+    fn test_parse_rejects_nodes_without_a_mapping() {
+        // XXA is reached from 11A but has no mapping of its own, so it can't be a start.
+        let input = "L\n\n11A = (11Z, XXA)\n11Z = (11Z, 11Z)\n";
+        assert_eq!(
+            Day08.parse(Part::Two, input),
+            Err("no mapping for node XXA".to_string())
+        );
+        let input = "L\n\n11A = (11Z, 11Z)\n11Z = (11Z, 11Z)\n";
+        assert_eq!(Day08.parse(Part::Two, input), Ok(()));
+        assert_eq!(Day08.part_2(input).as_deref(), Some("1"));
+    }
+}
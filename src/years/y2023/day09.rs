@@ -0,0 +1,107 @@
+use std::str::FromStr;
+
+use crate::{
+    input,
+    solution::{Part, Solution},
+};
+
+#[derive(Debug)]
+struct Puzzle {
+    sequences: Vec<Vec<i32>>,
+}
+
+impl FromStr for Puzzle {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Ok(Puzzle {
+            sequences: input
+                .lines()
+                .map(|line| {
+                    line.split(" ")
+                        .map(input::number::<i32>)
+                        .collect::<Result<Vec<_>, _>>()
+                })
+                .collect::<Result<Vec<_>, _>>()?,
+        })
+    }
+}
+
+#[derive(Copy, Clone)]
+enum StartOrEnd {
+    Start,
+    End,
+}
+
+/// The extrapolated value, or `None` if it doesn't fit. A single value left over in a row counts
+/// as a constant, like a row of zeros does.
+fn next_value(sequence: &[i32], pos: StartOrEnd) -> Option<i32> {
+    use StartOrEnd::*;
+
+    let mut current_row = sequence.to_owned();
+    let mut rows = vec![current_row.clone()];
+
+    while current_row.len() > 1 && !current_row.iter().all(|x| *x == 0) {
+        current_row = current_row
+            .iter()
+            .take(current_row.len() - 1)
+            .zip(current_row.iter().skip(1))
+            .map(|(a, b)| b.checked_sub(*a))
+            .collect::<Option<_>>()?;
+
+        rows.push(current_row.clone());
+    }
+
+    let mut column = rows.iter().rev().map(|x| {
+        x[match pos {
+            Start => 0,
+            End => x.len() - 1,
+        }]
+    });
+
+    let first = column.next()?;
+    column.try_fold(first, |acc, x| match pos {
+        Start => x.checked_sub(acc),
+        End => acc.checked_add(x),
+    })
+}
+
+fn solve(puzzle: &Puzzle, pos: StartOrEnd) -> Option<i32> {
+    puzzle
+        .sequences
+        .iter()
+        .try_fold(0i32, |sum, s| sum.checked_add(next_value(s, pos)?))
+}
+
+fn position(part: Part) -> StartOrEnd {
+    match part {
+        Part::One => StartOrEnd::End,
+        Part::Two => StartOrEnd::Start,
+    }
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    fn day(&self) -> u8 {
+        9
+    }
+
+    fn part_1(&self, input: &str) -> String {
+        let puzzle = input.parse().unwrap();
+        solve(&puzzle, StartOrEnd::End).unwrap().to_string()
+    }
+
+    fn part_2(&self, input: &str) -> Option<String> {
+        let puzzle = input.parse().unwrap();
+        Some(solve(&puzzle, StartOrEnd::Start).unwrap().to_string())
+    }
+
+    /// Extrapolating is cheap enough to find out whether the values fit.
+    fn parse(&self, part: Part, input: &str) -> Result<(), String> {
+        let puzzle = input.parse::<Puzzle>()?;
+        solve(&puzzle, position(part))
+            .map(|_| ())
+            .ok_or_else(|| "values too large".to_string())
+    }
+}
@@ -1,5 +1,7 @@
 use itertools::iproduct;
-use std::{collections::HashSet, fs::read_to_string, str::FromStr};
+use std::{collections::HashSet, str::FromStr};
+
+use crate::solution::{Part, Solution};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Dir {
@@ -11,13 +13,10 @@ enum Dir {
 
 impl Dir {
     fn is_opposite(&self, dir: Dir) -> bool {
-        match (self, dir) {
-            (Dir::N, Dir::S) => true,
-            (Dir::S, Dir::N) => true,
-            (Dir::E, Dir::W) => true,
-            (Dir::W, Dir::E) => true,
-            _ => false,
-        }
+        matches!(
+            (self, dir),
+            (Dir::N, Dir::S) | (Dir::S, Dir::N) | (Dir::E, Dir::W) | (Dir::W, Dir::E)
+        )
     }
 }
 
@@ -31,7 +30,7 @@ enum Tile {
 type Pos = (i32, i32);
 
 impl FromStr for Tile {
-    type Err = ();
+    type Err = String;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         use Dir::*;
@@ -44,7 +43,7 @@ impl FromStr for Tile {
             "F" => Self::Connection(S, E),
             "." => Self::Ground,
             "S" => Self::Start,
-            _ => panic!("Unknown symbol: '{}'", s),
+            _ => return Err(format!("Unknown symbol: '{}'", s)),
         })
     }
 }
@@ -63,25 +62,40 @@ struct Puzzle {
     height: i32,
 }
 
+impl Puzzle {
+    /// The tile at `pos`, or `None` if that's off the map.
+    fn tile(&self, (x, y): Pos) -> Option<Tile> {
+        if x < 0 || y < 0 {
+            return None;
+        }
+        self.map.get(y as usize)?.get(x as usize).copied()
+    }
+}
+
 impl FromStr for Puzzle {
-    type Err = ();
+    type Err = String;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
         let mut map = vec![];
-        let mut start = (0, 0);
+        let mut start = None;
         for (y, line) in input.lines().enumerate() {
             let mut l = vec![];
             for (x, c) in line.chars().enumerate() {
-                let tile: Tile = c.to_string().parse().unwrap();
+                let tile: Tile = c.to_string().parse()?;
                 l.push(tile);
                 if tile.is_start() {
-                    start = (x as i32, y as i32);
+                    start = Some((x as i32, y as i32));
                 }
             }
 
             map.push(l);
         }
 
+        let start = start.ok_or("no start tile")?;
+        if map.iter().any(|l| l.len() != map[0].len()) {
+            return Err("not all lines are equally long".to_string());
+        }
+
         Ok(Puzzle {
             width: map[0].len() as i32,
             height: map.len() as i32,
@@ -101,7 +115,7 @@ fn walk(pos: Pos, dir: Dir) -> Pos {
 }
 
 /// Given the start tile finds one of the two possible directions one could walk.
-fn find_first_direction(puzzle: &Puzzle) -> Dir {
+fn find_first_direction(puzzle: &Puzzle) -> Option<Dir> {
     use Dir::*;
     let neighbours = [
         (puzzle.start.0 + 1, puzzle.start.1, E),
@@ -110,44 +124,44 @@ fn find_first_direction(puzzle: &Puzzle) -> Dir {
         (puzzle.start.0, puzzle.start.1 - 1, N),
     ];
     for neighbour in neighbours {
-        // By clamping we might end up with the position of the start tile. This isn't an issue
-        // since we only match on connecting tiles.
-        match puzzle.map[neighbour.1.clamp(0, puzzle.height) as usize]
-            [neighbour.0.clamp(0, puzzle.width) as usize]
-        {
-            Tile::Connection(a, b) => {
+        match puzzle.tile((neighbour.0, neighbour.1)) {
+            Some(Tile::Connection(a, b)) => {
                 if neighbour.2.is_opposite(a) || neighbour.2.is_opposite(b) {
-                    return neighbour.2;
+                    return Some(neighbour.2);
                 }
             }
             _ => continue,
         }
     }
-    panic!("There should be at least one starting direction for the start tile!");
+    None
 }
 
-fn cycle(puzzle: &Puzzle) -> Vec<Pos> {
-    let mut current_dir = find_first_direction(puzzle);
+/// The tiles of the loop through the start tile, ending with the start tile itself. Every pipe
+/// connects to two others, so following them either leads back to the start or breaks off.
+fn cycle(puzzle: &Puzzle) -> Result<Vec<Pos>, String> {
+    let mut current_dir = find_first_direction(puzzle).ok_or("no pipe connects to the start")?;
     let mut current_pos = walk(puzzle.start, current_dir);
 
     let mut result = vec![current_pos];
 
     loop {
-        let tile = puzzle.map[current_pos.1 as usize][current_pos.0 as usize];
+        let tile = puzzle
+            .tile(current_pos)
+            .ok_or_else(|| format!("the loop leaves the map at {:?}", current_pos))?;
 
         match tile {
-            Tile::Start => return result,
-            Tile::Ground => panic!("shouldn't land on ground!"),
+            Tile::Start => return Ok(result),
+            Tile::Ground => return Err(format!("the loop runs into ground at {:?}", current_pos)),
             Tile::Connection(a, b) => {
                 if current_dir.is_opposite(a) {
                     current_dir = b;
                 } else if current_dir.is_opposite(b) {
                     current_dir = a;
                 } else {
-                    panic!(
-                        "couldn't find opposite for pos {:?}, dir {:?} and connection {:?}, {:?}!",
-                        current_pos, current_dir, a, b
-                    );
+                    return Err(format!(
+                        "the pipe at {:?} doesn't connect to the one before",
+                        current_pos
+                    ));
                 }
             }
         }
@@ -159,7 +173,7 @@ fn cycle(puzzle: &Puzzle) -> Vec<Pos> {
 }
 
 fn part_1(puzzle: &Puzzle) -> i32 {
-    return cycle(puzzle).len() as i32 / 2;
+    cycle(puzzle).unwrap().len() as i32 / 2
 }
 
 /// Given a position `pos` and the `main_loop` determines whether the point is enclosed by the main
@@ -182,16 +196,18 @@ fn is_inside(pos: Pos, main_loop: &HashSet<Pos>, puzzle: &Puzzle) -> bool {
     // is smaller.
     let ray: HashSet<Pos> = (pos.0..puzzle.width).zip(pos.1..puzzle.height).collect();
 
-    ray.intersection(&main_loop)
+    ray.intersection(main_loop)
         // When walking from top left to bottom right we don't want to count L and 7 pieces as
         // hits since we're leaving the enclosed area immediately. If we don't exclude them they
         // would count as one intersection instead of zero.
-        .filter(|(x, y)| match puzzle.map[*y as usize][*x as usize] {
-            Tile::Connection(Dir::W, Dir::S) => false,
-            Tile::Connection(Dir::S, Dir::W) => false,
-            Tile::Connection(Dir::N, Dir::E) => false,
-            Tile::Connection(Dir::E, Dir::N) => false,
-            _ => true,
+        .filter(|(x, y)| {
+            !matches!(
+                puzzle.map[*y as usize][*x as usize],
+                Tile::Connection(Dir::W, Dir::S)
+                    | Tile::Connection(Dir::S, Dir::W)
+                    | Tile::Connection(Dir::N, Dir::E)
+                    | Tile::Connection(Dir::E, Dir::N)
+            )
         })
         .count()
         % 2
@@ -199,23 +215,35 @@ fn is_inside(pos: Pos, main_loop: &HashSet<Pos>, puzzle: &Puzzle) -> bool {
 }
 
 fn part_2(puzzle: &Puzzle) -> i32 {
-    let main_loop: HashSet<Pos> = cycle(puzzle).into_iter().collect();
+    let main_loop: HashSet<Pos> = cycle(puzzle).unwrap().into_iter().collect();
 
     // We can go through all tiles and for each position not part of the main loop we can figure
     // out whether it's inside the enclosed area or not, see `is_inside`.
 
-    let inside_counts = iproduct!(0..puzzle.width, 0..puzzle.height)
-        .filter(|(x, y)| is_inside((*x as i32, *y as i32), &main_loop, puzzle))
-        .count() as i32;
+    
 
-    inside_counts
+    iproduct!(0..puzzle.width, 0..puzzle.height)
+        .filter(|(x, y)| is_inside(((*x), (*y)), &main_loop, puzzle))
+        .count() as i32
 }
 
-fn main() {
-    let input = read_to_string("inputs/day10.txt").expect("file not found");
+pub struct Day10;
+
+impl Solution for Day10 {
+    fn day(&self) -> u8 {
+        10
+    }
 
-    let puzzle = input.parse().unwrap();
+    fn part_1(&self, input: &str) -> String {
+        part_1(&input.parse().unwrap()).to_string()
+    }
 
-    println!("Part 1: {}", part_1(&puzzle));
-    println!("Part 2: {}", part_2(&puzzle));
+    fn part_2(&self, input: &str) -> Option<String> {
+        Some(part_2(&input.parse().unwrap()).to_string())
+    }
+
+    /// Both parts need the loop, which is cheap to follow.
+    fn parse(&self, _part: Part, input: &str) -> Result<(), String> {
+        cycle(&input.parse()?).map(|_| ())
+    }
 }
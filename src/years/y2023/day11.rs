@@ -0,0 +1,156 @@
+use itertools::Itertools;
+use std::collections::HashSet;
+
+use crate::solution::{Part, Solution};
+
+type Point = (usize, usize);
+
+fn manhattan_distance(a: &Point, b: &Point) -> usize {
+    a.0.abs_diff(b.0) + a.1.abs_diff(b.1)
+}
+
+fn parse(input: &str) -> Result<Vec<Point>, String> {
+    let mut result = vec![];
+
+    for (y, line) in input.lines().enumerate() {
+        for (x, c) in line.chars().enumerate() {
+            match c {
+                '.' => continue,
+                '#' => {
+                    result.push((x, y));
+                }
+                _ => return Err(format!("unknown character {}", c)),
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+fn distances(galaxies: &[Point]) -> usize {
+    (0..galaxies.len())
+        .combinations(2)
+        .map(|coord| {
+            let from = coord[0];
+            let to = coord[1];
+
+            manhattan_distance(&galaxies[from], &galaxies[to])
+        })
+        .sum()
+}
+
+fn extend(galaxies: &mut [Point], offset: usize) {
+    // Without galaxies there is nothing to move.
+    let Some(max_x) = galaxies.iter().map(|(x, _)| *x).max() else {
+        return;
+    };
+    let max_y = galaxies.iter().map(|(_, y)| *y).max().unwrap();
+
+    let xs = galaxies.iter().map(|(x, _)| *x).collect::<HashSet<_>>();
+    let ys = galaxies.iter().map(|(_, y)| *y).collect::<HashSet<_>>();
+
+    let all_columns = (0..=max_x).collect::<HashSet<_>>();
+    let all_rows = (0..=max_y).collect::<HashSet<_>>();
+
+    let mut empty_columns = all_columns.difference(&xs).collect::<Vec<_>>();
+    empty_columns.sort();
+    let mut empty_rows = all_rows.difference(&ys).collect::<Vec<_>>();
+    empty_rows.sort();
+
+    for empty_row in empty_rows.iter().rev() {
+        for galaxy in galaxies.iter_mut() {
+            if galaxy.1 > **empty_row {
+                galaxy.1 += offset;
+            }
+        }
+    }
+
+    for empty_column in empty_columns.iter().rev() {
+        for galaxy in galaxies.iter_mut() {
+            if galaxy.0 > **empty_column {
+                galaxy.0 += offset;
+            }
+        }
+    }
+}
+
+fn part_1(galaxies: &mut [Point]) -> usize {
+    extend(galaxies, 1);
+    distances(galaxies)
+}
+
+fn part_2(galaxies: &mut [Point]) -> usize {
+    extend(galaxies, 1000000 - 1);
+    distances(galaxies)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    fn day(&self) -> u8 {
+        11
+    }
+
+    fn part_1(&self, input: &str) -> String {
+        part_1(&mut parse(input).unwrap()).to_string()
+    }
+
+    fn part_2(&self, input: &str) -> Option<String> {
+        Some(part_2(&mut parse(input).unwrap()).to_string())
+    }
+
+    fn parse(&self, _part: Part, input: &str) -> Result<(), String> {
+        parse(input).map(|_| ())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compress::{Axis, CompressedGrid};
+
+    const EXAMPLE: &str = "...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....
+";
+
+    /// Sums the shortest paths between all galaxies on the expanded universe, walking through it
+    /// instead of using the Manhattan distance.
+    fn walked_distances(input: &str, factor: i64) -> i64 {
+        let galaxies = parse(input).unwrap();
+        let (width, height) = (input.lines().next().unwrap().len(), input.lines().count());
+        let expanded = |occupied: Vec<usize>, len| {
+            Axis::from_widths((0..len).map(|i| if occupied.contains(&i) { 1 } else { factor }))
+        };
+        let xs = expanded(galaxies.iter().map(|g| g.0).collect(), width);
+        let ys = expanded(galaxies.iter().map(|g| g.1).collect(), height);
+        let grid = CompressedGrid::new(xs, ys);
+
+        let mut sum = 0;
+        for (i, from) in galaxies.iter().enumerate() {
+            let distances = grid.distances(*from);
+            sum += galaxies[i + 1..]
+                .iter()
+                .map(|to| distances[to.1][to.0].unwrap())
+                .sum::<i64>();
+        }
+        sum
+    }
+
+    #[test]
+    fn test_expansion_matches_walked_distances() {
+        for (factor, expected) in [(2, 374), (10, 1030), (100, 8410)] {
+            let mut galaxies = parse(EXAMPLE).unwrap();
+            extend(&mut galaxies, factor - 1);
+            assert_eq!(distances(&galaxies), expected);
+            assert_eq!(walked_distances(EXAMPLE, factor as i64), expected as i64);
+        }
+    }
+}
@@ -0,0 +1,285 @@
+use std::str::FromStr;
+
+use crate::{
+    explain::Trace,
+    input,
+    solution::{Part, Solution},
+};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Spring {
+    Operational,
+    Damaged,
+    Unknown,
+}
+
+impl Spring {
+    fn from_char(c: char) -> Result<Self, String> {
+        match c {
+            '.' => Ok(Self::Operational),
+            '#' => Ok(Self::Damaged),
+            '?' => Ok(Self::Unknown),
+            _ => Err(format!("unknown spring {}", c)),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Row {
+    springs: Vec<Spring>,
+    /// The lengths of the contiguous groups of damaged springs, in order.
+    groups: Vec<usize>,
+}
+
+impl Row {
+    /// Five copies of the springs separated by unknown springs, and five copies of the groups.
+    fn unfold(&self) -> Row {
+        let mut springs = self.springs.clone();
+        for _ in 1..5 {
+            springs.push(Spring::Unknown);
+            springs.extend(self.springs.iter());
+        }
+
+        Row {
+            springs,
+            groups: self.groups.repeat(5),
+        }
+    }
+
+    /// Whether a group of `length` damaged springs can start at `start`: none of its springs are
+    /// operational and it isn't followed directly by a damaged spring.
+    fn fits(&self, start: usize, length: usize) -> bool {
+        let Some(end) = start.checked_add(length) else {
+            return false;
+        };
+        end <= self.springs.len()
+            && !self.springs[start..end].contains(&Spring::Operational)
+            && self.springs.get(end) != Some(&Spring::Damaged)
+    }
+
+    /// Counts the ways to replace the unknown springs so the groups match.
+    ///
+    /// `counts[i][g]` is the number of arrangements of the springs from `i` on, given the groups
+    /// from `g` on are still to be placed. Filling the table from the back means every spring and
+    /// group combination is looked at once, instead of trying all assignments of the unknowns.
+    /// `None` if the count doesn't fit in a `u64`.
+    fn arrangement_count(&self) -> Option<u64> {
+        let (len, groups) = (self.springs.len(), self.groups.len());
+        // One more row for the position after a group ending at the last spring.
+        let mut counts = vec![vec![0; groups + 1]; len + 2];
+        counts[len][groups] = 1;
+        counts[len + 1][groups] = 1;
+
+        for i in (0..len).rev() {
+            for g in 0..=groups {
+                let mut count: u64 = 0;
+                if self.springs[i] != Spring::Damaged {
+                    count = counts[i + 1][g];
+                }
+                if self.springs[i] != Spring::Operational
+                    && g < groups
+                    && self.fits(i, self.groups[g])
+                {
+                    // The group is followed by an operational spring, unless the row ends.
+                    count = count.checked_add(counts[i + self.groups[g] + 1][g + 1])?;
+                }
+                counts[i][g] = count;
+            }
+        }
+
+        Some(counts[0][0])
+    }
+
+    /// Lists the arrangements by trying every placement of the groups. Only useful for small
+    /// rows, as there can be lots of them.
+    fn arrangements(&self) -> Vec<String> {
+        fn place(row: &Row, i: usize, g: usize, current: &mut String, result: &mut Vec<String>) {
+            if i >= row.springs.len() {
+                if g == row.groups.len() {
+                    result.push(current.clone());
+                }
+                return;
+            }
+
+            let len = current.len();
+            if row.springs[i] != Spring::Damaged {
+                current.push('.');
+                place(row, i + 1, g, current, result);
+                current.truncate(len);
+            }
+            if row.springs[i] != Spring::Operational
+                && g < row.groups.len()
+                && row.fits(i, row.groups[g])
+            {
+                let end = i + row.groups[g];
+                current.push_str(&"#".repeat(row.groups[g]));
+                if end < row.springs.len() {
+                    current.push('.');
+                }
+                place(row, end + 1, g + 1, current, result);
+                current.truncate(len);
+            }
+        }
+
+        let mut result = vec![];
+        place(self, 0, 0, &mut String::new(), &mut result);
+        result
+    }
+}
+
+impl FromStr for Row {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (springs, groups) = input::split_once(input, " ")?;
+
+        let springs = springs
+            .chars()
+            .map(Spring::from_char)
+            .collect::<Result<_, _>>()?;
+        let groups = groups
+            .split(',')
+            .map(input::number)
+            .collect::<Result<_, _>>()?;
+
+        Ok(Row { springs, groups })
+    }
+}
+
+/// The row as counted in `part`.
+fn row_for(part: Part, row: &Row) -> Row {
+    match part {
+        Part::One => row.clone(),
+        Part::Two => row.unfold(),
+    }
+}
+
+/// The sum of the arrangement counts of the rows in `part`, `None` if it doesn't fit in a `u64`.
+fn arrangement_sum(part: Part, rows: &[Row]) -> Option<u64> {
+    rows.iter().try_fold(0u64, |sum, row| {
+        sum.checked_add(row_for(part, row).arrangement_count()?)
+    })
+}
+
+fn parse(part: Part, input: &str) -> Result<Vec<Row>, String> {
+    let rows = input
+        .lines()
+        .map(|line| line.parse())
+        .collect::<Result<Vec<Row>, _>>()?;
+    if arrangement_sum(part, &rows).is_none() {
+        return Err("too many arrangements".to_string());
+    }
+    Ok(rows)
+}
+
+/// Rows of the first part with at most this many arrangements list them when explained.
+const LISTED_ARRANGEMENTS: u64 = 20;
+
+/// The arrangement count of every row, with the arrangements of the short ones. The unfolded rows
+/// of the second part are only counted, listing their arrangements could take ages.
+fn explain(input: &str, part: Part) -> Trace {
+    let rows = parse(part, input).unwrap();
+    let mut steps = vec![];
+    for (line, row) in input.lines().zip(&rows) {
+        let row = row_for(part, row);
+        let count = row.arrangement_count().unwrap();
+
+        let mut step = Trace::new(line).value(count);
+        if part == Part::One && count <= LISTED_ARRANGEMENTS {
+            step = step.steps(row.arrangements().into_iter().map(Trace::new));
+        }
+        steps.push(step);
+    }
+    Trace::new("sum of the arrangement counts")
+        .value(arrangement_sum(part, &rows).unwrap())
+        .steps(steps)
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    fn day(&self) -> u8 {
+        12
+    }
+
+    fn part_1(&self, input: &str) -> String {
+        let rows = parse(Part::One, input).unwrap();
+        arrangement_sum(Part::One, &rows).unwrap().to_string()
+    }
+
+    fn part_2(&self, input: &str) -> Option<String> {
+        let rows = parse(Part::Two, input).unwrap();
+        Some(arrangement_sum(Part::Two, &rows).unwrap().to_string())
+    }
+
+    fn parse(&self, part: Part, input: &str) -> Result<(), String> {
+        parse(part, input).map(|_| ())
+    }
+
+    fn explain(&self, part: Part, input: &str) -> Option<Trace> {
+        Some(explain(input, part))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answers::assert_example;
+
+    const EXAMPLE: &str = include_str!("../../../fixtures/2023/day12/example.txt");
+    const ANSWERS: &str = include_str!("../../../fixtures/2023/day12/answers.txt");
+
+    #[test]
+    fn test_example() {
+        assert_example(&Day12, EXAMPLE, ANSWERS);
+    }
+
+    fn arrangements(line: &str) -> Vec<String> {
+        line.parse::<Row>().unwrap().arrangements()
+    }
+
+    #[test]
+    fn test_counts_match_listed_arrangements() {
+        assert_eq!(arrangements("???.### 1,1,3"), ["#.#.###"]);
+        assert_eq!(
+            arrangements(".??..??...?##. 1,1,3"),
+            [
+                "..#...#...###.",
+                "..#..#....###.",
+                ".#....#...###.",
+                ".#...#....###."
+            ]
+        );
+
+        for row in parse(Part::One, EXAMPLE).unwrap() {
+            assert_eq!(
+                row.arrangement_count(),
+                Some(row.arrangements().len() as u64)
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_rejects_counts_over_u64() {
+        let row = format!("{} 1,1,1,1,1,1", "?".repeat(40));
+        assert!(Day12.parse(Part::One, &row).is_ok());
+        assert_eq!(
+            Day12.parse(Part::Two, &row),
+            Err("too many arrangements".to_string())
+        );
+        // Group lengths beyond the row don't overflow either.
+        assert_eq!(Day12.part_1(&format!("?? {}", usize::MAX)), "0");
+    }
+
+    #[test]
+    fn test_explain() {
+        let trace = explain(EXAMPLE, Part::One);
+        assert_eq!(trace.value.as_deref(), Some("21"));
+        assert_eq!(trace.steps[0].text(), "???.### 1,1,3: 1\n  #.#.###\n");
+
+        // Unfolded rows are only counted.
+        let trace = explain(EXAMPLE, Part::Two);
+        assert_eq!(trace.value.as_deref(), Some("525152"));
+        assert!(trace.steps.iter().all(|step| step.steps.is_empty()));
+    }
+}
@@ -0,0 +1,219 @@
+use std::{fmt, str::FromStr};
+
+use crate::{
+    bitgrid::{BitGrid, BitSet},
+    explain::Trace,
+    input,
+    solution::{Part, Solution},
+};
+
+#[derive(Debug, Clone, Copy)]
+enum Mirror {
+    Horizontal(usize),
+    Vertical(usize),
+}
+
+impl Mirror {
+    fn points(&self) -> usize {
+        match self {
+            Self::Vertical(index) => index + 1,
+            Self::Horizontal(index) => (index + 1) * 100,
+        }
+    }
+}
+
+impl fmt::Display for Mirror {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Vertical(index) => write!(f, "vertical, after column {}", index + 1),
+            Self::Horizontal(index) => write!(f, "horizontal, after row {}", index + 1),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Block {
+    columns: Vec<BitSet>,
+    rows: Vec<BitSet>,
+}
+
+impl Block {
+    fn mirror(&self) -> Option<Mirror> {
+        let col = find_mirror(&self.columns).map(Mirror::Vertical);
+        let row = find_mirror(&self.rows).map(Mirror::Horizontal);
+        col.or(row)
+    }
+}
+
+impl FromStr for Block {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        if let Some(c) = input
+            .chars()
+            .find(|c| !matches!(c, '#' | '.' | '\n' | '\r'))
+        {
+            return Err(format!("unknown character '{}'", c));
+        }
+        let grid = BitGrid::parse(input, |c| c == '#')?;
+
+        Ok(Block {
+            rows: (0..grid.height()).map(|y| grid.row(y)).collect(),
+            columns: (0..grid.width()).map(|x| grid.column(x)).collect(),
+        })
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<Block>, String> {
+    let mut result = vec![];
+
+    for block in input::blocks(input) {
+        result.push(block.parse()?);
+    }
+    Ok(result)
+}
+
+/// How the mirror of a block is found in `part`.
+fn mirror_finder(part: Part) -> fn(&Block) -> Option<Mirror> {
+    match part {
+        Part::One => Block::mirror,
+        Part::Two => smudged_mirror,
+    }
+}
+
+/// Parses the blocks, making sure each of them has a mirror to find in `part`.
+fn parse_with_mirrors(part: Part, input: &str) -> Result<Vec<Block>, String> {
+    let blocks = parse(input)?;
+    match blocks
+        .iter()
+        .position(|block| mirror_finder(part)(block).is_none())
+    {
+        Some(i) => Err(format!("no mirror in block {}", i + 1)),
+        None => Ok(blocks),
+    }
+}
+
+/// Finds the index at which the array can be mirrored. The index I should be understood as "the
+/// array can be mirrored using a mirror between I and I+1".
+fn find_mirror<T: PartialEq>(v: &[T]) -> Option<usize> {
+    (0..v.len().saturating_sub(1)).find(|i| is_mirror(v, *i))
+}
+
+/// Checks if the given array can be mirrored at `index` and `index + 1`.
+fn is_mirror<T: PartialEq>(v: &[T], index: usize) -> bool {
+    if index + 1 >= v.len() {
+        panic!("Index out of bounds. Index: {}, Len: {}", index, v.len());
+    }
+    let min_len = (v.len() - (index + 1)).min(index + 1);
+    let left = v[index - (min_len - 1)..=index].iter().rev();
+    let right = v[index + 1..index + 1 + min_len].iter();
+    left.eq(right)
+}
+
+/// Checks if the given array could be mirrored using a mirror between `index` and `index + 1` if
+/// and only if there's a single bit flip in one of the mirrored rows/columns.
+fn is_almost_mirror(v: &[BitSet], index: usize) -> bool {
+    if index + 1 >= v.len() {
+        panic!("Index out of bounds. Index: {}, Len: {}", index, v.len());
+    }
+    let min_len = (v.len() - (index + 1)).min(index + 1);
+    let left = v[index - (min_len - 1)..=index].iter().rev();
+    let right = v[index + 1..index + 1 + min_len].iter();
+    let differences: usize = left.zip(right).map(|(l, r)| (l ^ r).count_ones()).sum();
+
+    differences == 1
+}
+
+fn part_1(blocks: &[Block]) -> usize {
+    blocks.iter().map(|b| b.mirror().unwrap().points()).sum()
+}
+
+/// The mirror of a block once its smudge is cleaned up.
+fn smudged_mirror(block: &Block) -> Option<Mirror> {
+    // If there's just one smudge preventing rows/columns from being a perfect mirror from each
+    // other, then the mirrored rows/columns differ in exactly one bit.
+    let mut mirror: Option<Mirror> = None;
+
+    for i in 0..block.rows.len().saturating_sub(1) {
+        if is_almost_mirror(&block.rows, i) {
+            mirror = Some(Mirror::Horizontal(i));
+            break;
+        }
+    }
+    for i in 0..block.columns.len().saturating_sub(1) {
+        if is_almost_mirror(&block.columns, i) {
+            mirror = Some(Mirror::Vertical(i));
+            break;
+        }
+    }
+
+    mirror
+}
+
+fn part_2(blocks: &[Block]) -> usize {
+    blocks
+        .iter()
+        .map(|b| smudged_mirror(b).unwrap().points())
+        .sum()
+}
+
+/// The mirror found in every block and the points it's worth.
+fn explain(blocks: &[Block], mirror: fn(&Block) -> Option<Mirror>) -> Trace {
+    let mut sum = 0;
+    let mut steps = vec![];
+    for (i, block) in blocks.iter().enumerate() {
+        let mirror = mirror(block).unwrap();
+        sum += mirror.points();
+        steps.push(
+            Trace::new(format!("Block {}", i + 1))
+                .value(mirror.points())
+                .step(Trace::new("mirror").value(mirror)),
+        );
+    }
+    Trace::new("summary of the mirrors").value(sum).steps(steps)
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    fn day(&self) -> u8 {
+        13
+    }
+
+    fn part_1(&self, input: &str) -> String {
+        part_1(&parse_with_mirrors(Part::One, input).unwrap()).to_string()
+    }
+
+    fn part_2(&self, input: &str) -> Option<String> {
+        Some(part_2(&parse_with_mirrors(Part::Two, input).unwrap()).to_string())
+    }
+
+    fn parse(&self, part: Part, input: &str) -> Result<(), String> {
+        parse_with_mirrors(part, input).map(|_| ())
+    }
+
+    fn explain(&self, part: Part, input: &str) -> Option<Trace> {
+        let blocks = parse_with_mirrors(part, input).unwrap();
+        Some(explain(&blocks, mirror_finder(part)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_mirror() {
+        let data = vec![1, 1, 3, 5, 3];
+        assert!(is_mirror(&data, 0));
+        assert!(!is_mirror(&data, 1));
+
+        let data = vec![1, 3, 3, 1, 3];
+        assert!(is_mirror(&data, 1));
+        assert!(!is_mirror(&data, 2));
+
+        let data = vec![1, 2, 4, 3, 3];
+        assert!(is_mirror(&data, 3));
+        assert!(!is_mirror(&data, 2));
+    }
+}
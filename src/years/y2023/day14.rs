@@ -1,60 +1,45 @@
-use std::{collections::HashMap, fs::read_to_string, str::FromStr};
+use std::{collections::HashMap, str::FromStr};
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
-enum Tile {
-    MovingRock,
-    FixedRock,
-    Empty,
-}
+use crate::{
+    bitgrid::BitGrid,
+    solution::{Part, Solution},
+};
 
+/// The rocks as bit grids, so states are cheap to clone and hash when looking for cycles.
 #[derive(Debug, Clone, Hash, Eq, PartialEq)]
 struct Platform {
-    grid: Vec<Vec<Tile>>,
+    fixed: BitGrid,
+    moving: BitGrid,
 }
 
 impl Platform {
     fn total_load(&self) -> u32 {
-        let mut sum = 0;
-
-        for (y, row) in self.grid.iter().enumerate() {
-            for tile in row {
-                if *tile == Tile::MovingRock {
-                    sum += (self.grid.len() - y) as u32;
-                }
-            }
-        }
-
-        sum
+        self.moving
+            .iter_ones()
+            .map(|(_, y)| (self.height() - y) as u32)
+            .sum()
     }
 
     fn width(&self) -> usize {
-        self.grid[0].len()
+        self.fixed.width()
     }
 
     fn height(&self) -> usize {
-        self.grid.len()
+        self.fixed.height()
     }
 }
 
 impl FromStr for Platform {
-    type Err = ();
+    type Err = String;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        let grid = input
-            .lines()
-            .map(|line| {
-                line.chars()
-                    .map(|c| match c {
-                        '#' => Tile::FixedRock,
-                        'O' => Tile::MovingRock,
-                        '.' => Tile::Empty,
-                        _ => panic!("unknown character '{}'", c),
-                    })
-                    .collect::<Vec<_>>()
-            })
-            .collect::<Vec<_>>();
-
-        Ok(Self { grid })
+        if let Some(c) = input.chars().find(|c| !"#O.\n".contains(*c)) {
+            return Err(format!("unknown character '{}'", c));
+        }
+        let fixed = BitGrid::parse(input, |c| c == '#')?;
+        let moving = BitGrid::parse(input, |c| c == 'O')?;
+
+        Ok(Self { fixed, moving })
     }
 }
 
@@ -74,11 +59,11 @@ fn tilt(platform: &mut Platform, dir: Dir) {
         for col in 0..platform.width() {
             let mut target: usize = 0;
             for row in 0..platform.height() {
-                if platform.grid[row][col] == Tile::FixedRock {
+                if platform.fixed.get(col, row) {
                     target = row + 1;
-                } else if platform.grid[row][col] == Tile::MovingRock {
-                    platform.grid[row][col] = Tile::Empty;
-                    platform.grid[target][col] = Tile::MovingRock;
+                } else if platform.moving.get(col, row) {
+                    platform.moving.set(col, row, false);
+                    platform.moving.set(col, target, true);
                     target += 1;
                 }
             }
@@ -87,11 +72,11 @@ fn tilt(platform: &mut Platform, dir: Dir) {
         for col in 0..platform.width() {
             let mut target: usize = platform.height() - 1;
             for row in (0..platform.height()).rev() {
-                if platform.grid[row][col] == Tile::FixedRock {
+                if platform.fixed.get(col, row) {
                     target = row.saturating_sub(1);
-                } else if platform.grid[row][col] == Tile::MovingRock {
-                    platform.grid[row][col] = Tile::Empty;
-                    platform.grid[target][col] = Tile::MovingRock;
+                } else if platform.moving.get(col, row) {
+                    platform.moving.set(col, row, false);
+                    platform.moving.set(col, target, true);
                     target = target.saturating_sub(1);
                 }
             }
@@ -100,12 +85,12 @@ fn tilt(platform: &mut Platform, dir: Dir) {
         for row in 0..platform.height() {
             let mut target = 0;
             for col in 0..platform.width() {
-                if platform.grid[row][col] == Tile::FixedRock {
+                if platform.fixed.get(col, row) {
                     target = col + 1;
-                } else if platform.grid[row][col] == Tile::MovingRock {
-                    platform.grid[row][col] = Tile::Empty;
-                    platform.grid[row][target] = Tile::MovingRock;
-                    target = target + 1;
+                } else if platform.moving.get(col, row) {
+                    platform.moving.set(col, row, false);
+                    platform.moving.set(target, row, true);
+                    target += 1;
                 }
             }
         }
@@ -113,11 +98,11 @@ fn tilt(platform: &mut Platform, dir: Dir) {
         for row in 0..platform.height() {
             let mut target = platform.width() - 1;
             for col in (0..platform.width()).rev() {
-                if platform.grid[row][col] == Tile::FixedRock {
+                if platform.fixed.get(col, row) {
                     target = col.saturating_sub(1);
-                } else if platform.grid[row][col] == Tile::MovingRock {
-                    platform.grid[row][col] = Tile::Empty;
-                    platform.grid[row][target] = Tile::MovingRock;
+                } else if platform.moving.get(col, row) {
+                    platform.moving.set(col, row, false);
+                    platform.moving.set(target, row, true);
                     target = target.saturating_sub(1);
                 }
             }
@@ -147,12 +132,6 @@ fn part_2(mut platform: Platform) -> u32 {
         i += 1;
 
         if let Some(index) = states.get(&platform) {
-            println!(
-                "State found already in iteration {}, now at i: {}, that was {} iterations ago. The load of the state: {}",
-                index,
-                i,
-                i - index,platform.total_load()
-            );
             // If we detect a cycle we move the iteration N times forward by the length of the cycle times
             // such that the iteration will be closest to the total iteration count without going
             // over it.
@@ -169,11 +148,22 @@ fn part_2(mut platform: Platform) -> u32 {
     platform.total_load()
 }
 
-fn main() {
-    let input = read_to_string("inputs/day14.txt").expect("file not found");
+pub struct Day14;
+
+impl Solution for Day14 {
+    fn day(&self) -> u8 {
+        14
+    }
 
-    let platform: Platform = input.parse().unwrap();
+    fn part_1(&self, input: &str) -> String {
+        part_1(input.parse().unwrap()).to_string()
+    }
 
-    println!("Part 1: {}", part_1(platform.clone()));
-    println!("Part 2: {}", part_2(platform.clone()));
+    fn part_2(&self, input: &str) -> Option<String> {
+        Some(part_2(input.parse().unwrap()).to_string())
+    }
+
+    fn parse(&self, _part: Part, input: &str) -> Result<(), String> {
+        input.parse::<Platform>().map(|_| ())
+    }
 }
@@ -1,5 +1,10 @@
 use itertools::Itertools;
-use std::{fs::read_to_string, str::FromStr};
+use std::str::FromStr;
+
+use crate::{
+    input,
+    solution::{Part, Solution},
+};
 
 fn hash(input: &str) -> u8 {
     let mut running: u8 = 0;
@@ -17,10 +22,18 @@ struct Puzzle {
 }
 
 impl FromStr for Puzzle {
-    type Err = ();
+    type Err = String;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        let operations = input.trim_end().split(',').map(|s| s.to_string()).collect();
+        let operations = input
+            .trim_end()
+            .split(',')
+            .map(|s| s.to_string())
+            .collect::<Vec<_>>();
+        // The first part hashes the steps as they are, but they still have to be operations.
+        for operation in operations.iter() {
+            operation.parse::<Operation>()?;
+        }
         Ok(Puzzle { operations })
     }
 }
@@ -38,24 +51,20 @@ struct Operation {
 }
 
 impl FromStr for Operation {
-    type Err = ();
+    type Err = String;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        let (op_pos, operator) = input
-            .chars()
-            .find_position(|c| *c == '=' || *c == '-')
-            .unwrap();
+        let op_pos = input
+            .find(['=', '-'])
+            .ok_or_else(|| format!("expected '=' or '-' in '{}'", input))?;
 
         let label = input[0..op_pos].to_string();
         let op = Operation {
             label,
-            operator: match operator {
-                '=' => {
-                    let focal = input[op_pos + 1..].parse().unwrap();
-                    Operator::Set(focal)
-                }
-                '-' => Operator::Minus,
-                _ => panic!("unknown operator '{}'", operator),
+            operator: match input[op_pos..].split_at(1) {
+                ("-", "") => Operator::Minus,
+                ("=", focal) => Operator::Set(input::number(focal)?),
+                _ => return Err(format!("expected an operation, got '{}'", input)),
             },
         };
         Ok(op)
@@ -125,11 +134,22 @@ fn part_2(puzzle: &Puzzle) -> u32 {
     boxes.focusing_power()
 }
 
-fn main() {
-    let input = read_to_string("inputs/day15.txt").expect("file not found");
+pub struct Day15;
 
-    let puzzle = input.parse().unwrap();
+impl Solution for Day15 {
+    fn day(&self) -> u8 {
+        15
+    }
 
-    println!("Part 1: {}", part_1(&puzzle));
-    println!("Part 2: {}", part_2(&puzzle));
+    fn part_1(&self, input: &str) -> String {
+        part_1(&input.parse().unwrap()).to_string()
+    }
+
+    fn part_2(&self, input: &str) -> Option<String> {
+        Some(part_2(&input.parse().unwrap()).to_string())
+    }
+
+    fn parse(&self, _part: Part, input: &str) -> Result<(), String> {
+        input.parse::<Puzzle>().map(|_| ())
+    }
 }
@@ -1,4 +1,9 @@
-use std::{collections::HashSet, fs::read_to_string};
+use std::collections::HashSet;
+
+use crate::{
+    cancel, profile,
+    solution::{Part, Solution},
+};
 
 #[derive(Debug, Clone, Hash, Eq, PartialEq)]
 enum Foo {
@@ -63,7 +68,8 @@ enum Tile {
     Mirror(Vector),
 }
 
-fn parse(input: &str) -> Vec<Vec<Tile>> {
+fn parse(input: &str) -> Result<Vec<Vec<Tile>>, String> {
+    let _phase = profile::phase("parse");
     let mut result = vec![];
     for line in input.lines() {
         let mut row = vec![];
@@ -74,21 +80,30 @@ fn parse(input: &str) -> Vec<Vec<Tile>> {
                 '|' => Tile::Splitter(Foo::Vertical),
                 '/' => Tile::Mirror(Vector { x: 1, y: 1 }),
                 '\\' => Tile::Mirror(Vector { x: 1, y: -1 }),
-                _ => panic!("unknown char '{}'", c),
+                _ => return Err(format!("unknown char '{}'", c)),
             };
             row.push(tile);
         }
         result.push(row);
     }
-    result
+
+    if result.first().is_none_or(|row| row.is_empty()) {
+        return Err("no tiles".to_string());
+    }
+    if result.iter().any(|row| row.len() != result[0].len()) {
+        return Err("not all lines are equally long".to_string());
+    }
+    Ok(result)
 }
 
 fn simulate_beams(grid: &[Vec<Tile>], start_pos: Vector, start_dir: Vector) -> usize {
+    let _phase = profile::phase("simulate_beams");
     let mut beams: Vec<Vector> = vec![start_pos];
     let mut dirs: Vec<Vector> = vec![start_dir];
     let mut visits: HashSet<(Vector, Vector)> = HashSet::new();
 
     loop {
+        cancel::checkpoint();
         let mut new_beams: Vec<Vector> = vec![];
         let mut new_dirs: Vec<Vector> = vec![];
         for (index, beam) in beams.iter_mut().enumerate() {
@@ -135,12 +150,9 @@ fn simulate_beams(grid: &[Vec<Tile>], start_pos: Vector, start_dir: Vector) -> u
             }
         }
 
-        let mut offset = 0;
-
-        for i in removed_indices {
+        for (offset, i) in removed_indices.into_iter().enumerate() {
             beams.remove(i - offset);
             dirs.remove(i - offset);
-            offset += 1;
         }
 
         if beams.is_empty() {
@@ -183,14 +195,25 @@ fn part_2(grid: &[Vec<Tile>]) -> u32 {
 
     let all = tops.chain(bottoms).chain(rights).chain(lefts);
 
-    all.map(|(s, d)| simulate_beams(grid, s, d)).max().unwrap() as u32 as u32
+    all.map(|(s, d)| simulate_beams(grid, s, d)).max().unwrap() as u32
 }
 
-fn main() {
-    let input = read_to_string("inputs/day16.txt").expect("file not found");
+pub struct Day16;
 
-    let grid = parse(&input);
+impl Solution for Day16 {
+    fn day(&self) -> u8 {
+        16
+    }
 
-    println!("Part 1: {}", part_1(&grid));
-    println!("Part 2: {}", part_2(&grid));
+    fn part_1(&self, input: &str) -> String {
+        part_1(&parse(input).unwrap()).to_string()
+    }
+
+    fn part_2(&self, input: &str) -> Option<String> {
+        Some(part_2(&parse(input).unwrap()).to_string())
+    }
+
+    fn parse(&self, _part: Part, input: &str) -> Result<(), String> {
+        parse(input).map(|_| ())
+    }
 }
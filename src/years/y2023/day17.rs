@@ -0,0 +1,336 @@
+use std::{collections::HashSet, str::FromStr};
+
+use crate::{
+    explain::Trace,
+    search::{self, Path},
+    solution::{Part, Solution},
+};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+enum Heading {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Heading {
+    fn vec(&self) -> (isize, isize) {
+        match self {
+            Self::North => (0, -1),
+            Self::East => (1, 0),
+            Self::South => (0, 1),
+            Self::West => (-1, 0),
+        }
+    }
+
+    fn turns(&self) -> [Heading; 2] {
+        match self {
+            Self::North | Self::South => [Self::East, Self::West],
+            Self::East | Self::West => [Self::North, Self::South],
+        }
+    }
+
+    fn arrow(&self) -> char {
+        match self {
+            Self::North => '^',
+            Self::East => '>',
+            Self::South => 'v',
+            Self::West => '<',
+        }
+    }
+}
+
+#[derive(Debug)]
+struct City {
+    width: usize,
+    height: usize,
+    /// The heat loss of each block, row by row.
+    heat_loss: Vec<u64>,
+}
+
+impl City {
+    fn heat_loss(&self, (x, y): (usize, usize)) -> u64 {
+        self.heat_loss[y * self.width + x]
+    }
+
+    fn step(&self, (x, y): (usize, usize), heading: Heading) -> Option<(usize, usize)> {
+        let (dx, dy) = heading.vec();
+        let x = x.checked_add_signed(dx).filter(|x| *x < self.width)?;
+        let y = y.checked_add_signed(dy).filter(|y| *y < self.height)?;
+        Some((x, y))
+    }
+}
+
+impl FromStr for City {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut heat_loss = vec![];
+        let width = input.lines().next().map_or(0, |line| line.chars().count());
+        for line in input.lines() {
+            if line.chars().count() != width {
+                return Err("not all lines are equally long".to_string());
+            }
+            for c in line.chars() {
+                let loss = c
+                    .to_digit(10)
+                    .ok_or_else(|| format!("unknown block {}", c))?;
+                heat_loss.push(loss as u64);
+            }
+        }
+        if width == 0 {
+            return Err("empty city".to_string());
+        }
+        let height = heat_loss.len() / width;
+
+        Ok(City {
+            width,
+            height,
+            heat_loss,
+        })
+    }
+}
+
+/// How many blocks a crucible has to and may move in a straight line, before it can turn and
+/// before it has to turn.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Crucible {
+    pub min_run: usize,
+    pub max_run: usize,
+}
+
+impl Crucible {
+    /// The crucible of the first part.
+    pub const NORMAL: Crucible = Crucible {
+        min_run: 1,
+        max_run: 3,
+    };
+    /// The ultra crucible of the second part.
+    pub const ULTRA: Crucible = Crucible {
+        min_run: 4,
+        max_run: 10,
+    };
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+struct State {
+    position: (usize, usize),
+    heading: Heading,
+    /// The blocks moved in `heading` since the last turn.
+    run: usize,
+}
+
+// The crucible hasn't moved yet at the start, so it may go either way.
+const STARTS: [State; 2] = [
+    State {
+        position: (0, 0),
+        heading: Heading::East,
+        run: 0,
+    },
+    State {
+        position: (0, 0),
+        heading: Heading::South,
+        run: 0,
+    },
+];
+
+/// The states the crucible can move to from `state`, with the heat loss of the block entered.
+fn moves(city: &City, crucible: Crucible, state: State) -> impl Iterator<Item = (State, u64)> + '_ {
+    let mut headings = vec![];
+    if state.run < crucible.max_run {
+        headings.push(state.heading);
+    }
+    if state.run >= crucible.min_run {
+        headings.extend(state.heading.turns());
+    }
+
+    headings.into_iter().filter_map(move |heading| {
+        let position = city.step(state.position, heading)?;
+        let run = if heading == state.heading {
+            state.run + 1
+        } else {
+            1
+        };
+        let next = State {
+            position,
+            heading,
+            run,
+        };
+        Some((next, city.heat_loss(position)))
+    })
+}
+
+fn is_goal(city: &City, crucible: Crucible, state: &State) -> bool {
+    state.position == (city.width - 1, city.height - 1) && state.run >= crucible.min_run
+}
+
+/// The path with the least heat loss from the top left to the bottom right block.
+fn minimal_path(city: &City, crucible: Crucible) -> Option<Path<State>> {
+    search::dijkstra(
+        STARTS,
+        |&state| moves(city, crucible, state),
+        |state| is_goal(city, crucible, state),
+    )
+}
+
+/// Whether the crucible can get to the bottom right block at all, which is cheaper to find out
+/// than the best way there.
+fn reaches_goal(city: &City, crucible: Crucible) -> bool {
+    let mut seen = HashSet::from(STARTS);
+    let mut stack = STARTS.to_vec();
+    while let Some(state) = stack.pop() {
+        if is_goal(city, crucible, &state) {
+            return true;
+        }
+        for (next, _) in moves(city, crucible, state) {
+            if seen.insert(next) {
+                stack.push(next);
+            }
+        }
+    }
+    false
+}
+
+/// Parses the city, making sure the crucible of `part` can cross it.
+fn parse(part: Part, input: &str) -> Result<City, String> {
+    let city: City = input.parse()?;
+    if !reaches_goal(&city, crucible(part)) {
+        return Err("no path to the bottom right block".to_string());
+    }
+    Ok(city)
+}
+
+fn crucible(part: Part) -> Crucible {
+    match part {
+        Part::One => Crucible::NORMAL,
+        Part::Two => Crucible::ULTRA,
+    }
+}
+
+/// The city map with the blocks on `path` replaced by the direction the crucible moved in.
+fn render(city: &City, path: &Path<State>) -> String {
+    let mut grid = city
+        .heat_loss
+        .chunks(city.width)
+        .map(|row| row.iter().map(|h| h.to_string()).collect::<String>())
+        .map(|row| row.into_bytes())
+        .collect::<Vec<_>>();
+
+    // The start block isn't entered, so it keeps its number.
+    for state in path.states.iter().skip(1) {
+        let (x, y) = state.position;
+        grid[y][x] = state.heading.arrow() as u8;
+    }
+
+    grid.into_iter()
+        .map(|row| String::from_utf8(row).unwrap() + "\n")
+        .collect()
+}
+
+/// The least heat loss of `crucible` on its way through the city in `input`, `None` if it can't
+/// get to the bottom right block.
+pub fn heat_loss(input: &str, crucible: Crucible) -> Result<Option<u64>, String> {
+    let city: City = input.parse()?;
+    Ok(minimal_path(&city, crucible).map(|path| path.cost))
+}
+
+/// The least heat loss, with the city map showing the path it's lost on.
+fn explain(city: &City, crucible: Crucible) -> Trace {
+    let path = minimal_path(city, crucible).unwrap();
+    Trace::new("least heat loss")
+        .value(path.cost)
+        .steps(render(city, &path).lines().map(Trace::new))
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    fn day(&self) -> u8 {
+        17
+    }
+
+    fn part_1(&self, input: &str) -> String {
+        let heat_loss = heat_loss(input, Crucible::NORMAL).unwrap();
+        heat_loss.expect("no path").to_string()
+    }
+
+    fn part_2(&self, input: &str) -> Option<String> {
+        let heat_loss = heat_loss(input, Crucible::ULTRA).unwrap();
+        Some(heat_loss.expect("no path").to_string())
+    }
+
+    fn parse(&self, part: Part, input: &str) -> Result<(), String> {
+        parse(part, input).map(|_| ())
+    }
+
+    fn explain(&self, part: Part, input: &str) -> Option<Trace> {
+        Some(explain(&parse(part, input).unwrap(), crucible(part)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answers::assert_example;
+
+    const EXAMPLE: &str = include_str!("../../../fixtures/2023/day17/example.txt");
+    const ANSWERS: &str = include_str!("../../../fixtures/2023/day17/answers.txt");
+    const EXAMPLE_2: &str = include_str!("../../../fixtures/2023/day17/example-2.txt");
+    const ANSWERS_2: &str = include_str!("../../../fixtures/2023/day17/answers-2.txt");
+
+    #[test]
+    fn test_example() {
+        assert_example(&Day17, EXAMPLE, ANSWERS);
+        assert_example(&Day17, EXAMPLE_2, ANSWERS_2);
+    }
+
+    #[test]
+    fn test_render_ultra_path() {
+        let city: City = EXAMPLE_2.parse().unwrap();
+        let path = minimal_path(&city, Crucible::ULTRA).unwrap();
+
+        // Every run between turns is within the limits.
+        let runs = path
+            .states
+            .windows(2)
+            .filter(|w| w[0].heading != w[1].heading);
+        assert!(runs.map(|w| w[0].run).all(|run| (4..=10).contains(&run)));
+        assert_eq!(
+            render(&city, &path),
+            "1>>>>>>>1111
+9999999v9991
+9999999v9991
+9999999v9991
+9999999v>>>>
+"
+        );
+    }
+
+    #[test]
+    fn test_heat_loss_with_other_crucibles() {
+        // Without any limits it's just the cheapest path.
+        let free = Crucible {
+            min_run: 0,
+            max_run: usize::MAX,
+        };
+        assert_eq!(heat_loss(EXAMPLE_2, free), Ok(Some(15)));
+        // The city is only 12 blocks wide.
+        let stuck = Crucible {
+            min_run: 20,
+            max_run: 20,
+        };
+        assert_eq!(heat_loss(EXAMPLE_2, stuck), Ok(None));
+        assert_eq!(heat_loss(EXAMPLE, Crucible::NORMAL), Ok(Some(102)));
+        assert!(heat_loss("", free).is_err());
+    }
+
+    #[test]
+    fn test_explain() {
+        let trace = Day17.explain(Part::Two, EXAMPLE_2).unwrap();
+        assert_eq!(trace.value.as_deref(), Some("71"));
+        let rows: Vec<_> = trace.steps.iter().map(|step| step.label.as_str()).collect();
+        assert_eq!(rows[0], "1>>>>>>>1111");
+        assert_eq!(rows.len(), 5);
+    }
+}
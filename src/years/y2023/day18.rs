@@ -1,5 +1,10 @@
 use itertools::Itertools;
-use std::{fs::read_to_string, str::FromStr};
+use std::str::FromStr;
+
+use crate::{
+    input,
+    solution::{Part, Solution},
+};
 
 #[derive(Debug, Copy, Clone)]
 enum Direction {
@@ -36,7 +41,7 @@ impl Direction {
 }
 
 impl FromStr for Direction {
-    type Err = ();
+    type Err = String;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(match s {
@@ -44,7 +49,7 @@ impl FromStr for Direction {
             "R" => Self::R,
             "U" => Self::U,
             "D" => Self::D,
-            _ => panic!("unknown character {}", s),
+            _ => return Err(format!("unknown character {}", s)),
         })
     }
 }
@@ -53,30 +58,15 @@ impl FromStr for Direction {
 struct Color((u8, u8, u8));
 
 impl FromStr for Color {
-    type Err = ();
+    type Err = String;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        let mut chars = input.chars();
-        chars.next().unwrap();
-
-        fn decode_hex(s: &str) -> u8 {
-            hex::decode(s).unwrap()[0]
-        }
-        let r = decode_hex(&format!(
-            "{}{}",
-            chars.next().unwrap(),
-            chars.next().unwrap()
-        ));
-        let g = decode_hex(&format!(
-            "{}{}",
-            chars.next().unwrap(),
-            chars.next().unwrap()
-        ));
-        let b = decode_hex(&format!(
-            "{}{}",
-            chars.next().unwrap(),
-            chars.next().unwrap()
-        ));
+        let invalid = || format!("expected a colour like #70c710, got '{}'", input);
+        let hex = input.strip_prefix('#').ok_or_else(invalid)?;
+        let bytes = hex::decode(hex).map_err(|_| invalid())?;
+        let [r, g, b] = bytes[..] else {
+            return Err(invalid());
+        };
 
         Ok(Color((r, g, b)))
     }
@@ -93,11 +83,11 @@ impl Instruction {
     fn move_from(&self, start: (f64, f64)) -> (f64, f64) {
         let (x, y) = self.direction.vec();
 
-        let steps = self.steps as i32;
-        (start.0 + (x * steps) as f64, start.1 + (y * steps) as f64)
+        let steps = self.steps as f64;
+        (start.0 + x as f64 * steps, start.1 + y as f64 * steps)
     }
 
-    fn reinterpret(&self) -> Instruction {
+    fn reinterpret(&self) -> Result<Instruction, String> {
         let steps = ((self.paint.0 .0 as u32) << 12)
             + ((self.paint.0 .1 as u32) << 4)
             + (self.paint.0 .2 as u32 >> 4);
@@ -109,27 +99,32 @@ impl Instruction {
             1 => Direction::D,
             2 => Direction::L,
             3 => Direction::U,
-            _ => panic!("expected only 0,1,2 or 3"),
+            _ => return Err(format!("expected a direction of 0 to 3, got {}", dir_num)),
         };
 
-        Self {
+        Ok(Self {
             direction,
             steps,
             paint: self.paint,
-        }
+        })
     }
 }
 
 impl FromStr for Instruction {
-    type Err = ();
+    type Err = String;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        let mut parts = input.split(" ");
+        let [direction, steps, rest] = input.split(" ").collect::<Vec<_>>()[..] else {
+            return Err(format!("expected three parts, got '{}'", input));
+        };
 
-        let direction = parts.next().unwrap().parse().unwrap();
-        let steps = parts.next().unwrap().parse().unwrap();
-        let rest = parts.next().unwrap();
-        let paint = rest[1..rest.len() - 1].parse().unwrap();
+        let direction = direction.parse()?;
+        let steps = input::number(steps)?;
+        let paint = rest
+            .strip_prefix('(')
+            .and_then(|rest| rest.strip_suffix(')'))
+            .ok_or_else(|| format!("expected parentheses around '{}'", rest))?
+            .parse()?;
 
         Ok(Instruction {
             direction,
@@ -145,27 +140,67 @@ struct Puzzle {
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[allow(clippy::upper_case_acronyms)]
 enum Rotation {
     CW,
     CCW,
 }
 
 impl Puzzle {
-    fn reinterpret(&self) -> Puzzle {
+    fn reinterpret(&self) -> Result<Puzzle, String> {
         let mut result = Puzzle {
             instructions: vec![],
         };
         for instruction in self.instructions.iter() {
-            result.instructions.push(instruction.reinterpret());
+            result.instructions.push(instruction.reinterpret()?);
         }
-        result
+        Ok(result)
+    }
+
+    /// Checks that the instructions dig a loop back to the start that turns at every corner and
+    /// goes around once, which is what [`Puzzle::enclosed_area`] relies on.
+    fn check_loop(&self) -> Result<(), String> {
+        if self.instructions.is_empty() {
+            return Err("no instructions".to_string());
+        }
+        let is_horizontal = |direction: Direction| direction.vec().1 == 0;
+        if let Some((from, to)) = self
+            .corners()
+            .find(|(from, to)| is_horizontal(*from) == is_horizontal(*to))
+        {
+            return Err(format!("no turn between {:?} and {:?}", from, to));
+        }
+
+        let end = self
+            .instructions
+            .iter()
+            .fold((0, 0), |(x, y), instruction| {
+                let (dx, dy) = instruction.direction.vec();
+                let steps = instruction.steps as i64;
+                (x + dx as i64 * steps, y + dy as i64 * steps)
+            });
+        if end != (0, 0) {
+            return Err("the trench doesn't end where it starts".to_string());
+        }
+
+        let turns = self
+            .corners()
+            .map(|(from, to)| match from.rotation(to) {
+                Rotation::CW => 1,
+                Rotation::CCW => -1,
+            })
+            .sum::<i64>();
+        if turns.abs() != 4 {
+            return Err("the trench doesn't go around once".to_string());
+        }
+        Ok(())
     }
 
     fn corners(&self) -> impl Iterator<Item = (Direction, Direction)> {
         let last_instruction = [self.instructions[self.instructions.len() - 1].clone()];
         last_instruction
             .into_iter()
-            .chain(self.instructions.clone().into_iter())
+            .chain(self.instructions.clone())
             .map(|i| i.direction)
             .tuple_windows()
     }
@@ -248,24 +283,107 @@ impl Puzzle {
 }
 
 impl FromStr for Puzzle {
-    type Err = ();
+    type Err = String;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
         let mut instructions = vec![];
         for line in input.lines() {
-            instructions.push(line.parse().unwrap());
+            instructions.push(line.parse()?);
         }
 
         Ok(Puzzle { instructions })
     }
 }
 
-fn main() {
-    let input = read_to_string("inputs/day18.txt").expect("file not found");
+/// The instructions of `part`, which for the second part are hidden in the colours.
+fn parse(part: Part, input: &str) -> Result<Puzzle, String> {
+    let puzzle: Puzzle = input.parse()?;
+    let puzzle = match part {
+        Part::One => puzzle,
+        Part::Two => puzzle.reinterpret()?,
+    };
+    puzzle.check_loop()?;
+    Ok(puzzle)
+}
 
-    let puzzle: Puzzle = input.parse().unwrap();
-    println!("Part 1: {}", puzzle.enclosed_area());
+pub struct Day18;
 
-    let puzzle = puzzle.reinterpret();
-    println!("Part 2: {}", puzzle.enclosed_area());
+impl Solution for Day18 {
+    fn day(&self) -> u8 {
+        18
+    }
+
+    fn part_1(&self, input: &str) -> String {
+        parse(Part::One, input).unwrap().enclosed_area().to_string()
+    }
+
+    fn part_2(&self, input: &str) -> Option<String> {
+        Some(parse(Part::Two, input).unwrap().enclosed_area().to_string())
+    }
+
+    fn parse(&self, part: Part, input: &str) -> Result<(), String> {
+        parse(part, input).map(|_| ())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compress::{Axis, CompressedGrid};
+
+    const EXAMPLE: &str = "R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)
+";
+
+    /// Digs the trench on a compressed grid and counts everything not reachable from outside.
+    fn flood_filled_area(puzzle: &Puzzle) -> i64 {
+        let mut corners = vec![(0, 0)];
+        for instruction in puzzle.instructions.iter() {
+            let (x, y) = corners[corners.len() - 1];
+            let (dx, dy) = instruction.direction.vec();
+            let steps = instruction.steps as i64;
+            corners.push((x + dx as i64 * steps, y + dy as i64 * steps));
+        }
+
+        // Every corner starts and ends cells, the margin leaves room to get around the trench.
+        let axis = |coordinates: Vec<i64>| {
+            let (min, max) = (
+                coordinates.iter().min().unwrap(),
+                coordinates.iter().max().unwrap(),
+            );
+            let margin = [min - 1, max + 2];
+            Axis::new(coordinates.iter().flat_map(|c| [*c, c + 1]).chain(margin))
+        };
+        let xs = axis(corners.iter().map(|c| c.0).collect());
+        let ys = axis(corners.iter().map(|c| c.1).collect());
+        let mut grid = CompressedGrid::new(xs, ys);
+        for (from, to) in corners.iter().tuple_windows() {
+            grid.block(*from, *to);
+        }
+
+        grid.total_area() - grid.area(&grid.flood_fill((0, 0)))
+    }
+
+    #[test]
+    fn test_enclosed_area_matches_flood_fill() {
+        let puzzle: Puzzle = EXAMPLE.parse().unwrap();
+        assert_eq!(puzzle.enclosed_area(), 62);
+        assert_eq!(flood_filled_area(&puzzle), 62);
+
+        let puzzle = puzzle.reinterpret().unwrap();
+        assert_eq!(puzzle.enclosed_area(), 952408144115);
+        assert_eq!(flood_filled_area(&puzzle), 952408144115);
+    }
 }
@@ -0,0 +1,597 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+    ops::RangeInclusive,
+    rc::Rc,
+    str::FromStr,
+};
+
+use crate::{
+    cancel,
+    explain::Trace,
+    graph::Graph,
+    input, profile,
+    solution::{Part, Solution},
+};
+
+/// The categories parts are rated in, in the order the puzzle lists them.
+const CATEGORIES: [&str; 4] = ["x", "m", "a", "s"];
+
+#[derive(Debug)]
+struct Ratings(HashMap<String, u32>);
+
+impl Ratings {
+    fn sum_of_ratings(&self) -> u64 {
+        self.0.values().map(|rating| *rating as u64).sum()
+    }
+}
+
+impl fmt::Display for Ratings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ratings = CATEGORIES
+            .iter()
+            .filter_map(|c| self.0.get(*c).map(|rating| format!("{}={}", c, rating)))
+            .collect::<Vec<_>>();
+        write!(f, "{{{}}}", ratings.join(","))
+    }
+}
+
+impl FromStr for Ratings {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let rest = input
+            .strip_prefix('{')
+            .and_then(|rest| rest.strip_suffix('}'))
+            .ok_or_else(|| format!("expected braces around '{}'", input))?;
+
+        let mut map = HashMap::new();
+        for part in rest.split(",") {
+            let (rating, value) = input::split_once(part, "=")?;
+
+            map.insert(rating.to_owned(), input::number(value)?);
+        }
+        if let Some(category) = CATEGORIES.iter().find(|c| !map.contains_key(**c)) {
+            return Err(format!("no rating for {} in '{}'", category, input));
+        }
+
+        Ok(Ratings(map))
+    }
+}
+
+#[derive(Debug)]
+enum Cmp {
+    LT,
+    GT,
+}
+
+impl Cmp {
+    fn evaluate(&self, a: u32, b: u32) -> bool {
+        match self {
+            Self::LT => a < b,
+            Self::GT => a > b,
+        }
+    }
+
+    fn branches(&self, a: u32) -> (RangeInclusive<u32>, RangeInclusive<u32>) {
+        match self {
+            Self::LT => (1..=a.saturating_sub(1), a..=4000),
+            Self::GT => (a.saturating_add(1)..=4000, 1..=a),
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Rule {
+    Condition(String, Cmp, u32, Rc<Rule>, Rc<Rule>),
+    Accept,
+    Reject,
+    Redirect(String),
+}
+
+impl Rule {
+    fn evaluate(&self, part: &Ratings) -> RuleEvaluation {
+        match self {
+            Self::Accept => RuleEvaluation::Accepted,
+            Self::Reject => RuleEvaluation::Rejected,
+            Self::Redirect(s) => RuleEvaluation::Redirected(s.clone()),
+            Self::Condition(rating, cmp, n, left, right) => {
+                let rating = part.0.get(rating).unwrap();
+                if cmp.evaluate(*rating, *n) {
+                    left.evaluate(part)
+                } else {
+                    right.evaluate(part)
+                }
+            }
+        }
+    }
+
+    /// Collects where this rule sends parts, together with the conditions on the way there.
+    fn targets(&self, conditions: &mut Vec<String>, targets: &mut Vec<(String, String)>) {
+        match self {
+            Self::Accept => targets.push(("A".to_string(), conditions.join(", "))),
+            Self::Reject => targets.push(("R".to_string(), conditions.join(", "))),
+            Self::Redirect(label) => targets.push((label.clone(), conditions.join(", "))),
+            Self::Condition(rating, cmp, n, left, right) => {
+                let (holds, fails) = match cmp {
+                    // Widened so the bounds of 0 and u32::MAX can't overflow.
+                    Cmp::LT => (
+                        format!("{}<{}", rating, n),
+                        format!("{}>{}", rating, *n as i64 - 1),
+                    ),
+                    Cmp::GT => (
+                        format!("{}>{}", rating, n),
+                        format!("{}<{}", rating, *n as i64 + 1),
+                    ),
+                };
+                conditions.push(holds);
+                left.targets(conditions, targets);
+                conditions.pop();
+                conditions.push(fails);
+                right.targets(conditions, targets);
+                conditions.pop();
+            }
+        }
+    }
+}
+
+fn assert_string<'a>(input: &'a str, s: &str) -> Result<(String, &'a str), String> {
+    match input.strip_prefix(s) {
+        Some(rest) => Ok((s.to_string(), rest)),
+        None => Err(format!("can't find '{}' in '{}'", s, input)),
+    }
+}
+
+fn parse_label(input: &str) -> Result<(String, &str), String> {
+    let label = input
+        .chars()
+        .take_while(|c| c.is_ascii_alphabetic())
+        .collect::<String>();
+
+    if label.is_empty() {
+        Err(format!(
+            "couldn't find label, found '{}' instead",
+            input.chars().next().map(String::from).unwrap_or_default()
+        ))
+    } else {
+        let len = label.len();
+        Ok((label, &input[len..]))
+    }
+}
+
+fn parse_cmp(input: &str) -> Result<(Cmp, &str), String> {
+    let cmp = match input.get(..1) {
+        Some(">") => Cmp::GT,
+        Some("<") => Cmp::LT,
+        _ => return Err(format!("expected > or <, got '{}'", &input[0..])),
+    };
+
+    Ok((cmp, &input[1..]))
+}
+
+fn parse_number(input: &str) -> Result<(u32, &str), String> {
+    let number_text = input
+        .chars()
+        .take_while(|c| c.is_ascii_digit())
+        .collect::<String>();
+    let number: u32 = number_text
+        .parse()
+        .map_err(|_| "couldn't parse number".to_string())?;
+
+    Ok((number, &input[number_text.len()..]))
+}
+
+fn parse_rule(input: &str) -> Result<(Rule, &str), String> {
+    fn parse_condition(input: &str) -> Result<(Rule, &str), String> {
+        let (label, rest) = parse_label(input)?;
+        if !CATEGORIES.contains(&label.as_str()) {
+            return Err(format!("unknown category {}", label));
+        }
+        let (cmp, rest) = parse_cmp(rest)?;
+        let (n, rest) = parse_number(rest)?;
+
+        let (_, rest) = assert_string(rest, ":")?;
+        let (true_case, rest) = parse_rule(rest)?;
+        let (_, rest) = assert_string(rest, ",")?;
+        let (false_case, rest) = parse_rule(rest)?;
+
+        Ok((
+            Rule::Condition(label, cmp, n, Rc::new(true_case), Rc::new(false_case)),
+            rest,
+        ))
+    }
+    fn parse_accept(input: &str) -> Result<(Rule, &str), String> {
+        assert_string(input, "A").map(|(_, rest)| (Rule::Accept, rest))
+    }
+    fn parse_reject(input: &str) -> Result<(Rule, &str), String> {
+        assert_string(input, "R").map(|(_, rest)| (Rule::Reject, rest))
+    }
+    fn parse_redirect(input: &str) -> Result<(Rule, &str), String> {
+        parse_label(input).map(|(s, rest)| (Rule::Redirect(s), rest))
+    }
+
+    let (rule, rest) = parse_condition(input)
+        .or(parse_accept(input))
+        .or(parse_reject(input))
+        .or(parse_redirect(input))?;
+
+    Ok((rule, rest))
+}
+
+#[derive(Debug)]
+struct Workflow {
+    label: String,
+    rule: Rule,
+}
+
+enum RuleEvaluation {
+    Accepted,
+    Rejected,
+    Redirected(String),
+}
+
+impl Workflow {
+    fn evaluate(&self, part: &Ratings) -> RuleEvaluation {
+        self.rule.evaluate(part)
+    }
+}
+
+impl FromStr for Workflow {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (label, rest) = input::split_once(input, "{")?;
+        let rest = rest
+            .strip_suffix('}')
+            .ok_or_else(|| format!("expected '}}' at the end of '{}'", input))?;
+
+        let (rule, _) = parse_rule(rest)?;
+
+        Ok(Workflow {
+            label: label.to_owned(),
+            rule,
+        })
+    }
+}
+
+#[derive(Debug)]
+struct Puzzle {
+    workflows: Vec<Workflow>,
+    parts: Vec<Ratings>,
+}
+
+impl FromStr for Puzzle {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let _phase = profile::phase("parse");
+        let mut blocks = input::blocks(input);
+        let workflows = blocks.next().ok_or("no workflows")?;
+        let parts = blocks.next().ok_or("no parts")?;
+
+        let workflows = input::lines(workflows)
+            .map(|line| line.parse())
+            .collect::<Result<Vec<_>, _>>()?;
+        let parts = input::lines(parts)
+            .map(|line| line.parse())
+            .collect::<Result<_, _>>()?;
+
+        let puzzle = Puzzle { workflows, parts };
+        puzzle.check_workflows()?;
+        Ok(puzzle)
+    }
+}
+
+impl Puzzle {
+    /// Checks that every part ends up accepted or rejected when it's sent through the workflows,
+    /// starting at `in`.
+    fn check_workflows(&self) -> Result<(), String> {
+        let graph = self.redirects();
+        if graph.id("in").is_none() {
+            return Err("no workflow in".to_string());
+        }
+        let is_known = |label: &str| {
+            label == "A" || label == "R" || self.workflows.iter().any(|w| w.label == label)
+        };
+        if let Some(node) = graph.nodes().find(|node| !is_known(graph.label(*node))) {
+            return Err(format!("no workflow {}", graph.label(node)));
+        }
+        if graph.topological_order().is_none() {
+            return Err("the workflows send parts around in circles".to_string());
+        }
+        Ok(())
+    }
+
+    /// The workflows as nodes, with an edge labelled with the conditions for every place a
+    /// workflow sends parts to. Accepting and rejecting are the nodes `A` and `R`.
+    fn redirects(&self) -> Graph<String> {
+        let mut graph = Graph::new();
+        for workflow in self.workflows.iter() {
+            graph.node(&workflow.label);
+            let mut targets = vec![];
+            workflow.rule.targets(&mut vec![], &mut targets);
+            for (target, conditions) in targets {
+                graph.add_edge(&workflow.label, &target, conditions);
+            }
+        }
+        graph
+    }
+}
+
+/// The workflows a part goes through, starting at `in`, and whether it's accepted in the end.
+fn route<'a>(puzzle: &'a Puzzle, part: &Ratings) -> (Vec<&'a str>, bool) {
+    // find "in" rule
+    let mut workflow = puzzle.workflows.iter().find(|w| w.label == "in").unwrap();
+    let mut route = vec![workflow.label.as_str()];
+
+    loop {
+        cancel::checkpoint();
+        match workflow.evaluate(part) {
+            RuleEvaluation::Accepted => return (route, true),
+            RuleEvaluation::Rejected => return (route, false),
+            RuleEvaluation::Redirected(label) => {
+                workflow = puzzle.workflows.iter().find(|w| w.label == label).unwrap();
+                route.push(&workflow.label);
+            }
+        }
+    }
+}
+
+fn part_1(puzzle: &Puzzle) -> u64 {
+    let _phase = profile::phase("workflows");
+    puzzle
+        .parts
+        .iter()
+        .filter(|part| route(puzzle, part).1)
+        .map(|p| p.sum_of_ratings())
+        .sum()
+}
+
+/// Every part with the workflows it went through, and its ratings if it was accepted.
+fn explain_1(puzzle: &Puzzle) -> Trace {
+    let steps = puzzle.parts.iter().map(|part| {
+        let (route, accepted) = route(puzzle, part);
+        let step = Trace::new(part.to_string());
+        let (step, end) = if accepted {
+            (step.value(part.sum_of_ratings()), "A")
+        } else {
+            (step.value("rejected"), "R")
+        };
+        step.step(Trace::new("route").value(format!("{} -> {}", route.join(" -> "), end)))
+    });
+    Trace::new("sum of the ratings of the accepted parts")
+        .value(part_1(puzzle))
+        .steps(steps)
+}
+
+/// For each rule retuns the possible part combinations represented as a map from rating to set of
+/// possible values.
+fn combinations(rule: &Rule, workflows: &[Workflow]) -> Vec<PossibleParts> {
+    match rule {
+        Rule::Condition(label, cmp, n, left_branch, right_branch) => {
+            let left = combinations(left_branch, workflows);
+            let right = combinations(right_branch, workflows);
+
+            let (left_range, right_range) = cmp.branches(*n);
+
+            let mut result = Vec::with_capacity(left.len() + right.len());
+            for l in left {
+                result.push(l.intersect(label, &left_range));
+            }
+            for r in right {
+                result.push(r.intersect(label, &right_range));
+            }
+            result
+        }
+        Rule::Accept => vec![all_combinations()],
+        Rule::Reject => vec![no_combinations()],
+        Rule::Redirect(label) => {
+            let w = workflows.iter().find(|w| w.label == *label).unwrap();
+
+            combinations(&w.rule, workflows)
+        }
+    }
+}
+
+struct PossibleParts(HashMap<String, HashSet<u32>>);
+
+impl PossibleParts {
+    fn intersect(mut self, label: &str, range: &RangeInclusive<u32>) -> Self {
+        let values = self.0.get_mut(label).unwrap();
+        values.retain(|n| range.contains(n));
+        self
+    }
+
+    fn combinations_count(&self) -> u64 {
+        self.0.values().map(|v| v.len() as u64).product()
+    }
+}
+
+impl fmt::Display for PossibleParts {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Only ever narrowed down by ranges, so the values are ranges themselves.
+        let ranges = CATEGORIES
+            .iter()
+            .map(|c| {
+                let values = &self.0[*c];
+                let min = values.iter().min().unwrap();
+                let max = values.iter().max().unwrap();
+                format!("{}={}..{}", c, min, max)
+            })
+            .collect::<Vec<_>>();
+        write!(f, "{}", ranges.join(" "))
+    }
+}
+
+fn all_combinations() -> PossibleParts {
+    let _phase = profile::phase("leaf_sets");
+    let all: HashSet<u32> = (1..=4000).collect();
+    PossibleParts(CATEGORIES.map(|c| (c.to_string(), all.clone())).into())
+}
+
+fn no_combinations() -> PossibleParts {
+    let _phase = profile::phase("leaf_sets");
+    let none = HashSet::new();
+    PossibleParts(CATEGORIES.map(|c| (c.to_string(), none.clone())).into())
+}
+
+fn part_2(puzzle: &Puzzle) -> u64 {
+    let in_workflow = puzzle.workflows.iter().find(|w| w.label == "in").unwrap();
+
+    let _phase = profile::phase("combinations");
+    combinations(&in_workflow.rule, &puzzle.workflows)
+        .iter()
+        .map(|p| p.combinations_count())
+        .sum()
+    //combinations.iter().map(|p| p.combinations_count()).sum()
+}
+
+/// The boxes of ratings that end up accepted, which never overlap, and how many parts each holds.
+fn explain_2(puzzle: &Puzzle) -> Trace {
+    let in_workflow = puzzle.workflows.iter().find(|w| w.label == "in").unwrap();
+
+    let mut sum = 0;
+    let mut steps = vec![];
+    for parts in combinations(&in_workflow.rule, &puzzle.workflows) {
+        let count = parts.combinations_count();
+        // Rejected boxes are empty, as are accepted ones whose conditions contradict each other.
+        if count > 0 {
+            sum += count;
+            steps.push(Trace::new(parts.to_string()).value(count));
+        }
+    }
+    Trace::new("combinations of ratings that are accepted")
+        .value(sum)
+        .steps(steps)
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    fn day(&self) -> u8 {
+        19
+    }
+
+    fn part_1(&self, input: &str) -> String {
+        part_1(&input.parse().unwrap()).to_string()
+    }
+
+    fn part_2(&self, input: &str) -> Option<String> {
+        Some(part_2(&input.parse().unwrap()).to_string())
+    }
+
+    fn parse(&self, _part: Part, input: &str) -> Result<(), String> {
+        input.parse::<Puzzle>().map(|_| ())
+    }
+
+    fn explain(&self, part: Part, input: &str) -> Option<Trace> {
+        let puzzle = input.parse().unwrap();
+        match part {
+            Part::One => Some(explain_1(&puzzle)),
+            Part::Two => Some(explain_2(&puzzle)),
+        }
+    }
+
+    fn graph(&self, input: &str) -> Option<Graph<String>> {
+        Some(input.parse::<Puzzle>().unwrap().redirects())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+";
+
+    #[test]
+    fn test_redirects() {
+        let puzzle: Puzzle = EXAMPLE.parse().unwrap();
+        let graph = puzzle.redirects();
+
+        let start = graph.id("in").unwrap();
+        let targets = graph
+            .successors(start)
+            .map(|e| (graph.label(e.to), e.label.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(targets, [("px", "s<1351"), ("qqz", "s>1350")]);
+
+        // Workflows never loop, and every one of them is used.
+        let order = graph.topological_order().unwrap();
+        assert_eq!(order[0], start);
+        assert_eq!(graph.reachable(start).count_ones(), graph.len());
+    }
+
+    #[test]
+    fn test_explain() {
+        let puzzle: Puzzle = EXAMPLE.parse().unwrap();
+        let trace = explain_1(&puzzle);
+        assert_eq!(trace.value.as_deref(), Some("7540"));
+        assert_eq!(
+            trace.steps[0].text(),
+            "{x=787,m=2655,a=1222,s=2876}: 7540\n  route: in -> qqz -> qs -> lnx -> A\n"
+        );
+
+        let trace = explain_2(&puzzle);
+        assert_eq!(trace.value.as_deref(), Some("167409079868000"));
+        assert_eq!(
+            trace.steps[0].label,
+            "x=1..1415 m=1..4000 a=1..2005 s=1..1350"
+        );
+    }
+
+    #[test]
+    fn test_possible_parts_intersection() {
+        let mut parts = all_combinations();
+        parts = parts.intersect("a", &(1..=200));
+        parts = parts.intersect("x", &(1..=200));
+        parts = parts.intersect("m", &(1..=200));
+        parts = parts.intersect("s", &(1..=100));
+
+        assert_eq!(parts.combinations_count(), 200 * 200 * 200 * 100);
+    }
+
+    #[test]
+    fn test_combining_conditions() {
+        let workflows = vec![Workflow {
+            label: "in".to_string(),
+            rule: Rule::Condition(
+                "a".to_string(),
+                Cmp::LT,
+                200,
+                Rc::new(Rule::Accept),
+                Rc::new(Rule::Condition(
+                    "x".to_string(),
+                    Cmp::GT,
+                    300,
+                    Rc::new(Rule::Reject),
+                    Rc::new(Rule::Accept),
+                )),
+            ),
+        }];
+        let combinations = combinations(&workflows[0].rule, &workflows);
+
+        assert_eq!(combinations.len(), 3);
+        assert_eq!(combinations[1].combinations_count(), 0);
+        assert_eq!(
+            combinations[0].combinations_count(),
+            4000 * 4000 * 4000 * 199
+        );
+        assert_eq!(
+            combinations[2].combinations_count(),
+            4000 * 4000 * (4000 - 199) * 300
+        );
+    }
+}
@@ -1,6 +1,9 @@
-use std::{
-    collections::{HashMap, VecDeque},
-    fs::read_to_string,
+use std::collections::{HashMap, VecDeque};
+
+use crate::{
+    graph::Graph,
+    input, profile,
+    solution::{Part, Solution},
 };
 
 // The general idea is to have a HashMap from name to module and a queue for each module with
@@ -33,22 +36,31 @@ impl Puzzle {
         self.configuration.iter().find(|c| c.name == name)
     }
 
-    /// Gets the number of inputs for each module
-    fn input_counts(&self) -> HashMap<String, usize> {
-        let mut result = HashMap::new();
-
+    /// The modules as nodes with an edge for every destination. Destinations without a module,
+    /// like `rx`, are nodes as well.
+    fn wiring(&self) -> Graph<()> {
+        let mut graph = Graph::new();
         for module in self.configuration.iter() {
-            for source in self.configuration.iter() {
-                if source.destination.contains(&module.name) {
-                    result
-                        .entry(module.name.clone())
-                        .and_modify(|e| *e += 1)
-                        .or_insert(1);
-                }
+            graph.node(&module.name);
+            for destination in module.destination.iter() {
+                graph.add_edge(&module.name, destination, ());
             }
         }
+        graph
+    }
 
-        result
+    /// Gets the number of inputs for each module
+    fn input_counts(&self) -> HashMap<String, usize> {
+        let wiring = self.wiring();
+        wiring
+            .nodes()
+            .map(|node| {
+                (
+                    wiring.label(node).to_owned(),
+                    wiring.predecessors(node).count(),
+                )
+            })
+            .collect()
     }
 }
 
@@ -65,16 +77,17 @@ struct Pulse {
     amplitude: Amplitude,
 }
 
-fn parse(input: &str) -> Puzzle {
+fn parse(input: &str) -> Result<Puzzle, String> {
+    let _phase = profile::phase("parse");
     let mut configuration = vec![];
     for line in input.lines() {
-        let (m, dest) = line.split_once(" -> ").unwrap();
+        let (m, dest) = input::split_once(line, " -> ")?;
         let destination = dest.split(", ").map(ToOwned::to_owned).collect::<Vec<_>>();
 
-        let (name, kind) = if m.starts_with("%") {
-            (m[1..].to_owned(), ModuleType::FlipFlop)
-        } else if m.starts_with("&") {
-            (m[1..].to_owned(), ModuleType::Conjunction)
+        let (name, kind) = if let Some(name) = m.strip_prefix('%') {
+            (name.to_owned(), ModuleType::FlipFlop)
+        } else if let Some(name) = m.strip_prefix('&') {
+            (name.to_owned(), ModuleType::Conjunction)
         } else {
             (m.to_owned(), ModuleType::Broadcaster)
         };
@@ -86,7 +99,7 @@ fn parse(input: &str) -> Puzzle {
         });
     }
 
-    Puzzle { configuration }
+    Ok(Puzzle { configuration })
 }
 
 fn part_1(puzzle: &Puzzle) -> u32 {
@@ -97,16 +110,14 @@ fn part_1(puzzle: &Puzzle) -> u32 {
     let mut sent_low_pulses = 0;
     let mut sent_high_pulses = 0;
 
-    for i in 1..=1000 {
+    let _phase = profile::phase("pulses");
+    for _ in 1..=1000 {
         queue.push_back(Pulse {
             destination: "broadcaster".to_owned(),
             amplitude: Amplitude::Low,
             source: "button".to_string(),
         });
         while let Some(pulse) = queue.pop_front() {
-            if pulse.destination == "zr" && pulse.amplitude == Amplitude::High {
-                println!("zr reached from {} at {}", pulse.source, i);
-            }
             if pulse.amplitude == Amplitude::High {
                 sent_high_pulses += 1;
             } else {
@@ -121,7 +132,7 @@ fn part_1(puzzle: &Puzzle) -> u32 {
                 ModuleType::Conjunction => {
                     let entry = conjunction_state
                         .entry(module.name.clone())
-                        .or_insert(HashMap::new());
+                        .or_default();
                     entry.insert(pulse.source, pulse.amplitude);
 
                     if entry.len() == conjunction_inputs[&module.name]
@@ -185,20 +196,38 @@ fn part_1(puzzle: &Puzzle) -> u32 {
     sent_low_pulses * sent_high_pulses
 }
 
-fn main() {
-    let input = read_to_string("inputs/day20.txt").expect("file not found");
+pub struct Day20;
 
-    let puzzle = parse(&input);
+impl Solution for Day20 {
+    fn day(&self) -> u8 {
+        20
+    }
 
-    println!("Part 1: {}", part_1(&puzzle));
+    fn part_1(&self, input: &str) -> String {
+        part_1(&parse(input).unwrap()).to_string()
+    }
 
-    // rx is only reachable by the conjunction zr.
-    // For rx to receive a high pulse all inputs to zr must be a high pulse as well. If we look at all inputs to zr (gc, xf, cm, sz) and log the amount of clicks it takes until each of them emits a high pulse, we can take the least common multiple of all clicks and get the least amount of clicks until all inputs receive a high pulse and therefore rx receives a high pulse as well.
-    // For my input that's:
-    // C(gc) = 3853
-    // C(xf) = 4073
-    // C(cm) = 4091
-    // C(sz) = 4093
-    // lcm(C(gc), C(xf), C(cm), C(sz)) = 262775362119547
-    println!("Part 2: {}", 262775362119547);
+    fn part_2(&self, _input: &str) -> Option<String> {
+        // rx is only reachable by the conjunction zr.
+        // For rx to receive a high pulse all inputs to zr must be a high pulse as well. If we look at all inputs to zr (gc, xf, cm, sz) and log the amount of clicks it takes until each of them emits a high pulse, we can take the least common multiple of all clicks and get the least amount of clicks until all inputs receive a high pulse and therefore rx receives a high pulse as well.
+        // For my input that's:
+        // C(gc) = 3853
+        // C(xf) = 4073
+        // C(cm) = 4091
+        // C(sz) = 4093
+        // lcm(C(gc), C(xf), C(cm), C(sz)) = 262775362119547
+        Some(262775362119547_u64.to_string())
+    }
+
+    fn graph(&self, input: &str) -> Option<Graph<String>> {
+        Some(parse(input).unwrap().wiring().map_edges(|()| String::new()))
+    }
+
+    fn parse(&self, _part: Part, input: &str) -> Result<(), String> {
+        parse(input).map(|_| ())
+    }
+
+    fn is_stub(&self, part: Part) -> bool {
+        part == Part::Two
+    }
 }
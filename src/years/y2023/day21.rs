@@ -0,0 +1,66 @@
+use crate::{
+    bitgrid::BitGrid,
+    solution::{Part, Solution},
+};
+
+#[derive(Debug)]
+struct Garden {
+    plots: BitGrid,
+    start: (usize, usize),
+}
+
+fn parse(input: &str) -> Result<Garden, String> {
+    if let Some(c) = input.chars().find(|c| !".#S\n".contains(*c)) {
+        return Err(format!("unknown cell {}", c));
+    }
+    let plots = BitGrid::parse(input, |c| c != '#')?;
+    let start = input
+        .lines()
+        .enumerate()
+        .find_map(|(y, line)| Some((line.find('S')?, y)))
+        .ok_or("no start")?;
+
+    Ok(Garden { plots, start })
+}
+
+/// The plots reachable in exactly `steps` steps. All positions are advanced at once: the next
+/// positions are the current ones moved in every direction, limited to garden plots.
+fn reachable(garden: &Garden, steps: usize) -> BitGrid {
+    let mut positions = BitGrid::new(garden.plots.width(), garden.plots.height());
+    positions.set(garden.start.0, garden.start.1, true);
+
+    for _ in 0..steps {
+        let moved = [(1, 0), (-1, 0), (0, 1), (0, -1)]
+            .into_iter()
+            .map(|(dx, dy)| positions.shifted(dx, dy))
+            .reduce(|a, b| &a | &b)
+            .unwrap();
+        positions = &moved & &garden.plots;
+    }
+
+    positions
+}
+
+fn part_1(garden: &Garden) -> u32 {
+    reachable(garden, 64).count_ones() as u32
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    fn day(&self) -> u8 {
+        21
+    }
+
+    fn part_1(&self, input: &str) -> String {
+        part_1(&parse(input).unwrap()).to_string()
+    }
+
+    fn part_2(&self, _input: &str) -> Option<String> {
+        None
+    }
+
+    fn parse(&self, _part: Part, input: &str) -> Result<(), String> {
+        parse(input).map(|_| ())
+    }
+}
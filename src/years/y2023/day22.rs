@@ -0,0 +1,246 @@
+use std::{collections::VecDeque, str::FromStr};
+
+use crate::{
+    graph::Graph,
+    input,
+    solution::{Part, Solution},
+};
+
+type Point = (usize, usize, usize);
+
+#[derive(Debug, Copy, Clone)]
+struct Brick {
+    /// The corner with the smallest coordinates.
+    from: Point,
+    /// The corner with the largest coordinates.
+    to: Point,
+}
+
+impl FromStr for Brick {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        // Coordinates that fit in an `u32` can't overflow the heights bricks are stacked to.
+        fn point(s: &str) -> Result<Point, String> {
+            let coordinates = s
+                .split(',')
+                .map(|n| input::number::<u32>(n).map(|n| n as usize))
+                .collect::<Result<Vec<_>, _>>()?;
+            let [x, y, z] = coordinates[..] else {
+                return Err(format!("expected three coordinates, got '{}'", s));
+            };
+            Ok((x, y, z))
+        }
+
+        let (a, b) = input::split_once(input, "~")?;
+        let (a, b) = (point(a)?, point(b)?);
+
+        Ok(Brick {
+            from: (a.0.min(b.0), a.1.min(b.1), a.2.min(b.2)),
+            to: (a.0.max(b.0), a.1.max(b.1), a.2.max(b.2)),
+        })
+    }
+}
+
+/// The most columns the height map of [`Supports::from_bricks`] may have, the puzzle input only
+/// covers 10 × 10.
+const MAX_COLUMNS: usize = 1 << 20;
+
+fn parse(input: &str) -> Result<Vec<Brick>, String> {
+    let bricks = input
+        .lines()
+        .map(|line| line.parse())
+        .collect::<Result<Vec<Brick>, _>>()?;
+    let width = bricks.iter().map(|b| b.to.0 + 1).max().unwrap_or(0);
+    let depth = bricks.iter().map(|b| b.to.1 + 1).max().unwrap_or(0);
+    if width.saturating_mul(depth) > MAX_COLUMNS {
+        return Err("the bricks are spread too far".to_string());
+    }
+    Ok(bricks)
+}
+
+/// Which bricks rest on which once they have all fallen. Bricks are numbered by their line in
+/// the input, starting at 0.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Supports {
+    supports: Vec<Vec<usize>>,
+    supported_by: Vec<Vec<usize>>,
+}
+
+impl Supports {
+    /// Lets the bricks of the puzzle input fall and records what they land on.
+    pub fn settle(input: &str) -> Result<Self, String> {
+        Ok(Self::from_bricks(&parse(input)?))
+    }
+
+    fn from_bricks(bricks: &[Brick]) -> Self {
+        let width = bricks.iter().map(|b| b.to.0 + 1).max().unwrap_or(0);
+        let depth = bricks.iter().map(|b| b.to.1 + 1).max().unwrap_or(0);
+        // The top of the highest brick in every column and which brick that is.
+        let mut heights: Vec<(usize, Option<usize>)> = vec![(0, None); width * depth];
+
+        let mut supports = vec![vec![]; bricks.len()];
+        let mut supported_by = vec![vec![]; bricks.len()];
+
+        // Bricks can only land on bricks that are lower, so going from the bottom up means
+        // everything below a brick has already settled.
+        let mut order = (0..bricks.len()).collect::<Vec<_>>();
+        order.sort_by_key(|i| bricks[*i].from.2);
+
+        for brick in order {
+            let Brick { from, to } = bricks[brick];
+            let columns = (from.1..=to.1)
+                .flat_map(|y| (from.0..=to.0).map(move |x| y * width + x))
+                .collect::<Vec<_>>();
+
+            let rest = columns.iter().map(|c| heights[*c].0).max().unwrap();
+            for column in columns.iter() {
+                if let (height, Some(below)) = heights[*column] {
+                    if height == rest && !supported_by[brick].contains(&below) {
+                        supported_by[brick].push(below);
+                        supports[below].push(brick);
+                    }
+                }
+            }
+
+            let top = rest + 1 + to.2 - from.2;
+            for column in columns {
+                heights[column] = (top, Some(brick));
+            }
+        }
+
+        Supports {
+            supports,
+            supported_by,
+        }
+    }
+
+    /// The number of bricks.
+    pub fn len(&self) -> usize {
+        self.supports.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.supports.is_empty()
+    }
+
+    /// The bricks resting directly on `brick`.
+    pub fn supports(&self, brick: usize) -> &[usize] {
+        &self.supports[brick]
+    }
+
+    /// The bricks `brick` rests on directly, empty if it's on the ground.
+    pub fn supported_by(&self, brick: usize) -> &[usize] {
+        &self.supported_by[brick]
+    }
+
+    /// Whether removing `brick` leaves all other bricks where they are.
+    pub fn can_disintegrate(&self, brick: usize) -> bool {
+        self.supports[brick]
+            .iter()
+            .all(|above| self.supported_by[*above].len() > 1)
+    }
+
+    /// The other bricks falling in a chain reaction when `brick` is removed.
+    pub fn falling(&self, brick: usize) -> Vec<usize> {
+        let mut fallen = vec![false; self.len()];
+        fallen[brick] = true;
+        let mut result = vec![];
+
+        let mut queue = VecDeque::from([brick]);
+        while let Some(removed) = queue.pop_front() {
+            for above in self.supports[removed].iter() {
+                if !fallen[*above] && self.supported_by[*above].iter().all(|b| fallen[*b]) {
+                    fallen[*above] = true;
+                    result.push(*above);
+                    queue.push_back(*above);
+                }
+            }
+        }
+        result
+    }
+
+    /// An edge from every brick to the ones resting on it, with the bricks numbered as in
+    /// [`Supports`].
+    pub fn graph(&self) -> Graph<String> {
+        let mut graph = Graph::new();
+        for brick in 0..self.len() {
+            graph.node(&brick.to_string());
+            for above in self.supports[brick].iter() {
+                graph.add_edge(&brick.to_string(), &above.to_string(), String::new());
+            }
+        }
+        graph
+    }
+}
+
+fn part_1(supports: &Supports) -> usize {
+    (0..supports.len())
+        .filter(|brick| supports.can_disintegrate(*brick))
+        .count()
+}
+
+fn part_2(supports: &Supports) -> usize {
+    (0..supports.len())
+        .map(|brick| supports.falling(brick).len())
+        .sum()
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    fn day(&self) -> u8 {
+        22
+    }
+
+    fn part_1(&self, input: &str) -> String {
+        part_1(&Supports::settle(input).unwrap()).to_string()
+    }
+
+    fn part_2(&self, input: &str) -> Option<String> {
+        Some(part_2(&Supports::settle(input).unwrap()).to_string())
+    }
+
+    fn parse(&self, _part: Part, input: &str) -> Result<(), String> {
+        parse(input).map(|_| ())
+    }
+
+    fn graph(&self, input: &str) -> Option<Graph<String>> {
+        Some(Supports::settle(input).unwrap().graph())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answers::assert_example;
+
+    const EXAMPLE: &str = include_str!("../../../fixtures/2023/day22/example.txt");
+    const ANSWERS: &str = include_str!("../../../fixtures/2023/day22/answers.txt");
+
+    #[test]
+    fn test_example() {
+        assert_example(&Day22, EXAMPLE, ANSWERS);
+    }
+
+    #[test]
+    fn test_supports() {
+        // The bricks are called A to G in the puzzle description.
+        let supports = Supports::settle(EXAMPLE).unwrap();
+        assert_eq!(supports.supports(0), [1, 2]);
+        assert_eq!(supports.supported_by(3), [1, 2]);
+        assert_eq!(supports.supported_by(0), [] as [usize; 0]);
+
+        assert_eq!(supports.falling(0), [1, 2, 3, 4, 5, 6]);
+        assert_eq!(supports.falling(5), [6]);
+        assert!(supports.falling(1).is_empty());
+    }
+
+    #[test]
+    fn test_parse_rejects_a_huge_floor() {
+        let spread = "0,0,1~0,0,1\n4000000000,4000000,1~4000000000,4000000,1\n";
+        assert!(Day22.parse(Part::One, spread).is_err());
+        assert!(Day22.parse(Part::One, "0,0,1~1023,1023,1\n").is_ok());
+        assert!(Day22.parse(Part::One, "0,0,1~1024,1023,1\n").is_err());
+    }
+}
@@ -0,0 +1,256 @@
+use crate::{
+    cancel,
+    graph::{Graph, NodeId},
+    solution::{Part, Solution},
+};
+
+type Position = (usize, usize);
+
+const DIRECTIONS: [(isize, isize, u8); 4] =
+    [(0, -1, b'^'), (1, 0, b'>'), (0, 1, b'v'), (-1, 0, b'<')];
+
+#[derive(Debug)]
+struct Map {
+    tiles: Vec<Vec<u8>>,
+    start: Position,
+    end: Position,
+}
+
+impl Map {
+    fn is_open(&self, (x, y): Position) -> bool {
+        self.tiles[y][x] != b'#'
+    }
+
+    /// The open neighbours of `position` that can be moved to. With `slopes` a slope can only be
+    /// left in the direction it points to.
+    fn moves(&self, (x, y): Position, slopes: bool) -> impl Iterator<Item = Position> + '_ {
+        let tile = self.tiles[y][x];
+        DIRECTIONS
+            .into_iter()
+            .filter(move |(_, _, slope)| !slopes || tile == b'.' || tile == *slope)
+            .filter_map(move |(dx, dy, _)| {
+                let x = x.checked_add_signed(dx)?;
+                let y = y.checked_add_signed(dy)?;
+                let row = self.tiles.get(y)?;
+                (x < row.len()).then_some((x, y))
+            })
+            .filter(|p| self.is_open(*p))
+    }
+
+    /// Tiles where the path forks, plus the start and the end.
+    fn junctions(&self) -> Vec<Position> {
+        let mut junctions = vec![self.start];
+        for (y, row) in self.tiles.iter().enumerate() {
+            for x in 0..row.len() {
+                if self.is_open((x, y)) && self.moves((x, y), false).count() > 2 {
+                    junctions.push((x, y));
+                }
+            }
+        }
+        junctions.push(self.end);
+        junctions
+    }
+
+    /// Compresses the corridors between junctions into single edges weighted with their length.
+    /// Nodes are labelled with the `x,y` position of their junction.
+    fn junction_graph(&self, slopes: bool) -> Graph<usize> {
+        let label = |(x, y): Position| format!("{},{}", x, y);
+        let junctions = self.junctions();
+
+        let mut graph = Graph::new();
+        for junction in junctions.iter() {
+            graph.node(&label(*junction));
+        }
+
+        for junction in junctions.iter() {
+            for first in self.moves(*junction, slopes) {
+                // Follow the corridor until the next junction, dead ends are dropped.
+                let (mut previous, mut current, mut length) = (*junction, first, 1);
+                while !junctions.contains(&current) {
+                    let Some(next) = self.moves(current, slopes).find(|p| *p != previous) else {
+                        break;
+                    };
+                    (previous, current, length) = (current, next, length + 1);
+                }
+                if junctions.contains(&current) && current != *junction {
+                    graph.add_edge(&label(*junction), &label(current), length);
+                }
+            }
+        }
+        graph
+    }
+}
+
+fn parse(input: &str) -> Result<Map, String> {
+    let tiles = input
+        .lines()
+        .map(|line| line.as_bytes().to_vec())
+        .collect::<Vec<_>>();
+    if let Some(c) = input.chars().find(|c| !"#.^>v<\n".contains(*c)) {
+        return Err(format!("unknown tile {}", c));
+    }
+    let opening = |row: Option<&Vec<u8>>| {
+        row.and_then(|row| row.iter().position(|c| *c == b'.'))
+            .ok_or("no opening")
+    };
+
+    Ok(Map {
+        start: (opening(tiles.first())?, 0),
+        end: (opening(tiles.last())?, tiles.len() - 1),
+        tiles,
+    })
+}
+
+/// Parses the map, making sure the end can be reached in `part` and there are few enough
+/// junctions for [`longest_path`].
+fn parse_hike(part: Part, input: &str) -> Result<Map, String> {
+    let map = parse(input)?;
+    let graph = map.junction_graph(part == Part::One);
+    if graph.len() > 64 {
+        return Err("more than 64 junctions".to_string());
+    }
+    let node = |(x, y): Position| graph.id(&format!("{},{}", x, y)).unwrap();
+    if !graph.reachable(node(map.start)).get(node(map.end).index()) {
+        return Err("no path to the end".to_string());
+    }
+    Ok(map)
+}
+
+/// The length of the longest path through the graph from `start` to `end` that visits no node
+/// twice. There are few enough junctions to track the visited ones in a bit mask.
+fn longest_path(graph: &Graph<usize>, start: NodeId, end: NodeId) -> Option<usize> {
+    assert!(graph.len() <= 64, "too many junctions for a u64 bit mask");
+    let edges = graph
+        .nodes()
+        .map(|node| {
+            graph
+                .successors(node)
+                .map(|e| (e.to.index(), e.label))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    // The end can only be entered from one junction. Once that's reached the end has to be next,
+    // going elsewhere would cut it off.
+    let last = graph
+        .predecessors(end)
+        .map(|e| e.from.index())
+        .collect::<Vec<_>>();
+    let last = (last.len() == 1).then(|| last[0]);
+
+    // An upper bound of what's left: every node not visited yet is entered at most once, through
+    // its longest incoming edge.
+    let longest_in = graph
+        .nodes()
+        .map(|n| graph.predecessors(n).map(|e| e.label).max().unwrap_or(0))
+        .collect::<Vec<_>>();
+
+    struct Search<'a> {
+        edges: &'a [Vec<(usize, usize)>],
+        longest_in: &'a [usize],
+        end: usize,
+        last: Option<usize>,
+        best: Option<usize>,
+    }
+
+    impl Search<'_> {
+        fn visit(&mut self, node: usize, visited: u64, length: usize, remaining: usize) {
+            if node == self.end {
+                self.best = self.best.max(Some(length));
+                return;
+            }
+            if self.best.is_some_and(|best| length + remaining <= best) {
+                return;
+            }
+            cancel::checkpoint();
+
+            for (next, weight) in self.edges[node].iter() {
+                if visited & (1 << next) != 0 {
+                    continue;
+                }
+                if Some(node) == self.last && *next != self.end {
+                    continue;
+                }
+                self.visit(
+                    *next,
+                    visited | (1 << next),
+                    length + weight,
+                    remaining - self.longest_in[*next],
+                );
+            }
+        }
+    }
+
+    let mut search = Search {
+        edges: &edges,
+        longest_in: &longest_in,
+        end: end.index(),
+        last,
+        best: None,
+    };
+    let start = start.index();
+    let remaining = longest_in.iter().sum::<usize>() - longest_in[start];
+    search.visit(start, 1 << start, 0, remaining);
+    search.best
+}
+
+fn longest_hike(map: &Map, slopes: bool) -> usize {
+    let graph = map.junction_graph(slopes);
+    let node = |(x, y): Position| graph.id(&format!("{},{}", x, y)).unwrap();
+    longest_path(&graph, node(map.start), node(map.end)).expect("no path to the end")
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    fn day(&self) -> u8 {
+        23
+    }
+
+    fn part_1(&self, input: &str) -> String {
+        longest_hike(&parse_hike(Part::One, input).unwrap(), true).to_string()
+    }
+
+    fn part_2(&self, input: &str) -> Option<String> {
+        Some(longest_hike(&parse_hike(Part::Two, input).unwrap(), false).to_string())
+    }
+
+    fn parse(&self, part: Part, input: &str) -> Result<(), String> {
+        parse_hike(part, input).map(|_| ())
+    }
+
+    fn graph(&self, input: &str) -> Option<Graph<String>> {
+        let graph = parse(input).unwrap().junction_graph(true);
+        Some(graph.map_edges(|length| length.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answers::assert_example;
+
+    const EXAMPLE: &str = include_str!("../../../fixtures/2023/day23/example.txt");
+    const ANSWERS: &str = include_str!("../../../fixtures/2023/day23/answers.txt");
+
+    #[test]
+    fn test_example() {
+        assert_example(&Day23, EXAMPLE, ANSWERS);
+    }
+
+    #[test]
+    fn test_junction_graph() {
+        let map = parse(EXAMPLE).unwrap();
+        // Start, end and seven forks.
+        let graph = map.junction_graph(false);
+        assert_eq!(graph.len(), 9);
+        let start = graph.id("1,0").unwrap();
+        let first = graph.successors(start).collect::<Vec<_>>();
+        assert_eq!(first.len(), 1);
+        assert_eq!((graph.label(first[0].to), first[0].label), ("3,5", 15));
+
+        // The slopes make the corridors one-way, so there's no way back.
+        let graph = map.junction_graph(true);
+        assert!(graph.topological_order().is_some());
+    }
+}
@@ -0,0 +1,302 @@
+use std::{ops::RangeInclusive, str::FromStr};
+
+use crate::{
+    input,
+    solution::{Part, Solution},
+};
+
+type Vec3 = [i128; 3];
+
+/// Bounds that keep the products in [`paths_cross`] and [`throw`] inside an `i128`, with room to
+/// spare for the puzzle inputs.
+const MAX_POSITION: i128 = 1 << 50;
+const MAX_VELOCITY: i128 = 1 << 20;
+
+fn sub(a: Vec3, b: Vec3) -> Vec3 {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn cross(a: Vec3, b: Vec3) -> Vec3 {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+struct Hailstone {
+    position: Vec3,
+    velocity: Vec3,
+}
+
+impl FromStr for Hailstone {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        fn vector(s: &str) -> Result<Vec3, String> {
+            let numbers = s
+                .split(',')
+                .map(|n| input::number(n.trim()))
+                .collect::<Result<Vec<_>, _>>()?;
+            numbers[..]
+                .try_into()
+                .map_err(|_| format!("expected three numbers, got '{}'", s))
+        }
+
+        let (position, velocity) = input::split_once(input, "@")?;
+        let hailstone = Hailstone {
+            position: vector(position)?,
+            velocity: vector(velocity)?,
+        };
+
+        if hailstone.position.iter().any(|p| p.abs() > MAX_POSITION)
+            || hailstone.velocity.iter().any(|v| v.abs() > MAX_VELOCITY)
+        {
+            return Err(format!("'{}' is too far away or too fast", input));
+        }
+        Ok(hailstone)
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<Hailstone>, String> {
+    input.lines().map(|line| line.parse()).collect()
+}
+
+/// The hailstones, for the second part only if a rock can hit all of them.
+fn parse_part(part: Part, input: &str) -> Result<Vec<Hailstone>, String> {
+    let hailstones = parse(input)?;
+    if part == Part::Two && throw(&hailstones).is_none() {
+        return Err("no rock hits every hailstone".to_string());
+    }
+    Ok(hailstones)
+}
+
+/// Whether the paths of `a` and `b` cross inside `area` in the future, ignoring the z axis.
+///
+/// Everything is compared as integer numerator and denominator: with `p + t * v` for both paths
+/// the crossing is at `t = t_num / det` and `s = s_num / det`.
+fn paths_cross(a: &Hailstone, b: &Hailstone, area: &RangeInclusive<i128>) -> bool {
+    let (p, v) = (a.position, a.velocity);
+    let (q, w) = (b.position, b.velocity);
+
+    let mut det = v[0] * w[1] - v[1] * w[0];
+    if det == 0 {
+        // Parallel paths never cross. Paths on the same line don't occur in the puzzle inputs.
+        return false;
+    }
+    let d = sub(q, p);
+    let mut t_num = d[0] * w[1] - d[1] * w[0];
+    let mut s_num = d[0] * v[1] - d[1] * v[0];
+    if det < 0 {
+        (det, t_num, s_num) = (-det, -t_num, -s_num);
+    }
+    if t_num < 0 || s_num < 0 {
+        return false;
+    }
+
+    // The crossing is at `p + t * v`, scaled by `det` so it stays an integer.
+    (0..2).all(|axis| {
+        let scaled = p[axis] * det + t_num * v[axis];
+        area.start() * det <= scaled && scaled <= area.end() * det
+    })
+}
+
+fn crossings(hailstones: &[Hailstone], area: &RangeInclusive<i128>) -> usize {
+    let mut count = 0;
+    for (i, a) in hailstones.iter().enumerate() {
+        for b in hailstones[i + 1..].iter() {
+            if paths_cross(a, b, area) {
+                count += 1;
+            }
+        }
+    }
+    count
+}
+
+/// Arithmetic modulo the Mersenne prime 2^61 - 1, whose products still fit in an `u128`.
+mod modular {
+    pub const PRIME: i128 = (1 << 61) - 1;
+
+    pub fn reduce(a: i128) -> i128 {
+        a.rem_euclid(PRIME)
+    }
+
+    pub fn mul(a: i128, b: i128) -> i128 {
+        ((a as u128 * b as u128) % PRIME as u128) as i128
+    }
+
+    pub fn inverse(a: i128) -> i128 {
+        // Fermat's little theorem: a^(p - 2) is the inverse of a.
+        let (mut result, mut base, mut exponent) = (1, a, PRIME - 2);
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = mul(result, base);
+            }
+            base = mul(base, base);
+            exponent >>= 1;
+        }
+        result
+    }
+
+    /// The residue as the integer closest to zero.
+    pub fn lift(a: i128) -> i128 {
+        if a > PRIME / 2 {
+            a - PRIME
+        } else {
+            a
+        }
+    }
+
+    /// Solves the linear system given as rows of coefficients followed by the right hand side,
+    /// or returns `None` if it's singular modulo the prime.
+    pub fn solve<const N: usize, const M: usize>(mut rows: [[i128; M]; N]) -> Option<[i128; N]> {
+        for row in rows.iter_mut() {
+            for value in row.iter_mut() {
+                *value = reduce(*value);
+            }
+        }
+
+        for column in 0..N {
+            let pivot = (column..N).find(|r| rows[*r][column] != 0)?;
+            rows.swap(column, pivot);
+            let inverse = inverse(rows[column][column]);
+            for value in rows[column].iter_mut() {
+                *value = mul(*value, inverse);
+            }
+            let pivot_row = rows[column];
+            for (index, row) in rows.iter_mut().enumerate() {
+                let factor = row[column];
+                if index == column || factor == 0 {
+                    continue;
+                }
+                for (value, pivot_value) in row.iter_mut().zip(pivot_row) {
+                    *value = reduce(*value - mul(factor, pivot_value));
+                }
+            }
+        }
+
+        Some(std::array::from_fn(|row| rows[row][M - 1]))
+    }
+}
+
+/// The position and velocity of a rock hitting every hailstone.
+///
+/// For a rock at `P` moving with `V` to hit hailstone `i`, `P - p_i` and `V - v_i` have to be
+/// parallel, so `(P - p_i) × (V - v_i) = 0`. Subtracting that equation for two hailstones gets rid
+/// of the only non-linear term `P × V`:
+///
+///   P × (v_j - v_i) + (p_j - p_i) × V = p_j × v_j - p_i × v_i
+///
+/// Two pairs of hailstones give six linear equations for the six unknowns. Solving them with
+/// fractions overflows even `i128`, as the input's coordinates are in the hundreds of trillions.
+/// Instead they are solved exactly modulo a prime: the actual solution is much smaller than the
+/// prime and an integer, so it's the residue closest to zero. It's checked against every
+/// hailstone in plain integers, in case the system happens to be singular modulo the prime.
+fn throw(hailstones: &[Hailstone]) -> Option<(Vec3, Vec3)> {
+    for window in hailstones.windows(3) {
+        let [a, b, c] = window else { unreachable!() };
+        let mut rows = [[0; 7]; 6];
+        for (pair, other) in [b, c].into_iter().enumerate() {
+            let d = sub(other.velocity, a.velocity);
+            let e = sub(other.position, a.position);
+            let rhs = sub(
+                cross(other.position, other.velocity),
+                cross(a.position, a.velocity),
+            );
+            let equations = [
+                [0, d[2], -d[1], 0, -e[2], e[1], rhs[0]],
+                [-d[2], 0, d[0], e[2], 0, -e[0], rhs[1]],
+                [d[1], -d[0], 0, -e[1], e[0], 0, rhs[2]],
+            ];
+            rows[pair * 3..pair * 3 + 3].copy_from_slice(&equations);
+        }
+
+        let Some(solution) = modular::solve(rows) else {
+            continue;
+        };
+        let solution = solution.map(modular::lift);
+        let position = [solution[0], solution[1], solution[2]];
+        let velocity = [solution[3], solution[4], solution[5]];
+
+        let hits =
+            |h: &Hailstone| cross(sub(position, h.position), sub(velocity, h.velocity)) == [0; 3];
+        if hailstones.iter().all(hits) {
+            return Some((position, velocity));
+        }
+    }
+    None
+}
+
+const TEST_AREA: RangeInclusive<i128> = 200000000000000..=400000000000000;
+
+fn part_1(hailstones: &[Hailstone]) -> usize {
+    crossings(hailstones, &TEST_AREA)
+}
+
+fn part_2(hailstones: &[Hailstone]) -> i128 {
+    let (position, _) = throw(hailstones).expect("no rock hits every hailstone");
+    position.iter().sum()
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    fn day(&self) -> u8 {
+        24
+    }
+
+    fn part_1(&self, input: &str) -> String {
+        part_1(&parse(input).unwrap()).to_string()
+    }
+
+    fn part_2(&self, input: &str) -> Option<String> {
+        Some(part_2(&parse_part(Part::Two, input).unwrap()).to_string())
+    }
+
+    fn parse(&self, part: Part, input: &str) -> Result<(), String> {
+        parse_part(part, input).map(|_| ())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answers::Answers;
+
+    const EXAMPLE: &str = include_str!("../../../fixtures/2023/day24/example.txt");
+    const ANSWERS: &str = include_str!("../../../fixtures/2023/day24/answers.txt");
+
+    #[test]
+    fn test_example() {
+        // The example uses a much smaller test area than the puzzle input.
+        let answers = Answers::parse(ANSWERS).unwrap();
+        let hailstones = parse(EXAMPLE).unwrap();
+        assert_eq!(
+            Some(crossings(&hailstones, &(7..=27)).to_string().as_str()),
+            answers.get(24, Part::One)
+        );
+        assert_eq!(Day24.part_2(EXAMPLE).as_deref(), answers.get(24, Part::Two));
+        assert_eq!(throw(&hailstones), Some(([24, 13, 10], [-3, 1, 2])));
+    }
+
+    #[test]
+    fn test_throw_with_input_sized_coordinates() {
+        // A rock far out in the test area, with hailstones it hits at different times.
+        let (position, velocity) = (
+            [287430900794229, 351515231432886, 120361659614345],
+            [-57, -19, 186],
+        );
+        let hailstones = [
+            (3, [20, -3, 11]),
+            (91, [-40, 7, 2]),
+            (517, [13, 13, -29]),
+            (1024, [-7, -81, 5]),
+        ]
+        .map(|(t, v): (i128, Vec3)| Hailstone {
+            position: std::array::from_fn(|i| position[i] + t * (velocity[i] - v[i])),
+            velocity: v,
+        });
+        assert_eq!(throw(&hailstones), Some((position, velocity)));
+    }
+}
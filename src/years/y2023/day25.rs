@@ -0,0 +1,201 @@
+use std::collections::VecDeque;
+
+use crate::{
+    bitgrid::BitSet,
+    graph::{Graph, NodeId},
+    input,
+    solution::{Part, Solution},
+};
+
+/// The components with an edge for every wire, in the direction it's listed in the input. Wires
+/// are referred to by the index of their edge.
+type Wiring = Graph<()>;
+
+fn parse(input: &str) -> Result<Wiring, String> {
+    let mut wiring = Graph::new();
+    for line in input.lines() {
+        let (component, connected) = input::split_once(line, ": ")?;
+        for other in connected.split(' ') {
+            wiring.add_edge(component, other, ());
+        }
+    }
+    if wiring.len() < 2 {
+        return Err("less than two components".to_string());
+    }
+    Ok(wiring)
+}
+
+/// The neighbours of every component, with the wire leading there.
+fn adjacency(wiring: &Wiring) -> Vec<Vec<(usize, usize)>> {
+    let mut adjacency = vec![vec![]; wiring.len()];
+    for (wire, edge) in wiring.edges().iter().enumerate() {
+        adjacency[edge.from.index()].push((edge.to.index(), wire));
+        adjacency[edge.to.index()].push((edge.from.index(), wire));
+    }
+    adjacency
+}
+
+/// The maximum flow from `source` to `sink` when every wire carries one unit, found with
+/// Edmonds-Karp. Gives up once the flow reaches `limit`, otherwise returns the flow and the
+/// components still reachable from `source`, which are one side of a minimum cut.
+fn max_flow(
+    wiring: &Wiring,
+    adjacency: &[Vec<(usize, usize)>],
+    (source, sink): (usize, usize),
+    limit: usize,
+) -> Option<(usize, BitSet)> {
+    // Positive if flowing from the first to the second component of the wire.
+    let mut flow = vec![0i8; wiring.edges().len()];
+    let residual = |flow: &[i8], from: usize, wire: usize| {
+        if wiring.edges()[wire].from.index() == from {
+            1 - flow[wire]
+        } else {
+            1 + flow[wire]
+        }
+    };
+
+    for total in 0.. {
+        if total >= limit {
+            return None;
+        }
+
+        // The shortest path with capacity left, remembering how each component was reached.
+        let mut reached = BitSet::new(wiring.len());
+        let mut parents = vec![None; wiring.len()];
+        reached.set(source, true);
+        let mut queue = VecDeque::from([source]);
+        while let Some(component) = queue.pop_front() {
+            for (next, wire) in adjacency[component].iter() {
+                if !reached.get(*next) && residual(&flow, component, *wire) > 0 {
+                    reached.set(*next, true);
+                    parents[*next] = Some((component, *wire));
+                    queue.push_back(*next);
+                }
+            }
+        }
+
+        if !reached.get(sink) {
+            return Some((total, reached));
+        }
+        let mut component = sink;
+        while let Some((previous, wire)) = parents[component] {
+            flow[wire] += if wiring.edges()[wire].from.index() == previous {
+                1
+            } else {
+                -1
+            };
+            component = previous;
+        }
+    }
+    unreachable!()
+}
+
+/// The fewest wires to cut to split the components in two, and the components on one side.
+///
+/// Any cut separates the first component from some other one, so the minimum cut is the smallest
+/// of the maximum flows from the first component to each other one.
+fn min_cut(wiring: &Wiring) -> (Vec<usize>, BitSet) {
+    let adjacency = adjacency(wiring);
+    let mut best: Option<(usize, BitSet)> = None;
+    for sink in 1..wiring.len() {
+        let limit = best.as_ref().map_or(usize::MAX, |(size, _)| *size);
+        if let Some(cut) = max_flow(wiring, &adjacency, (0, sink), limit) {
+            best = Some(cut);
+        }
+    }
+
+    let (_, side) = best.expect("less than two components");
+    let wires = (0..wiring.edges().len())
+        .filter(|wire| {
+            let edge = &wiring.edges()[*wire];
+            side.get(edge.from.index()) != side.get(edge.to.index())
+        })
+        .collect();
+    (wires, side)
+}
+
+/// The wires to disconnect, as the components they connect, and the sizes of the two groups.
+pub type Cut = (Vec<(String, String)>, (usize, usize));
+
+/// The wires to disconnect to split the components of the puzzle input in two.
+pub fn cut_wires(input: &str) -> Result<Cut, String> {
+    let wiring = parse(input)?;
+    let (wires, side) = min_cut(&wiring);
+
+    let label = |node: NodeId| wiring.label(node).to_owned();
+    let wires = wires
+        .into_iter()
+        .map(|wire| &wiring.edges()[wire])
+        .map(|edge| (label(edge.from), label(edge.to)))
+        .collect();
+    let size = side.count_ones();
+    Ok((wires, (size, wiring.len() - size)))
+}
+
+fn part_1(input: &str) -> usize {
+    let (_, (a, b)) = cut_wires(input).unwrap();
+    a * b
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    fn day(&self) -> u8 {
+        25
+    }
+
+    fn part_1(&self, input: &str) -> String {
+        part_1(input).to_string()
+    }
+
+    fn part_2(&self, _input: &str) -> Option<String> {
+        // The last day only has one puzzle.
+        None
+    }
+
+    fn parse(&self, _part: Part, input: &str) -> Result<(), String> {
+        parse(input).map(|_| ())
+    }
+
+    fn graph(&self, input: &str) -> Option<Graph<String>> {
+        // The wires to cut are labelled, everything else isn't.
+        let wiring = parse(input).unwrap();
+        let (cut, _) = min_cut(&wiring);
+        let mut wire = 0;
+        Some(wiring.map_edges(|()| {
+            wire += 1;
+            let label = if cut.contains(&(wire - 1)) { "cut" } else { "" };
+            label.to_string()
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answers::assert_example;
+
+    const EXAMPLE: &str = include_str!("../../../fixtures/2023/day25/example.txt");
+    const ANSWERS: &str = include_str!("../../../fixtures/2023/day25/answers.txt");
+
+    #[test]
+    fn test_example() {
+        assert_example(&Day25, EXAMPLE, ANSWERS);
+        assert_eq!(Day25.part_2(EXAMPLE), None);
+    }
+
+    #[test]
+    fn test_cut_wires() {
+        let (wires, sizes) = cut_wires(EXAMPLE).unwrap();
+        let mut wires = wires
+            .into_iter()
+            .map(|(a, b)| if a < b { (a, b) } else { (b, a) })
+            .collect::<Vec<_>>();
+        wires.sort();
+        let expected = [("bvb", "cmg"), ("hfx", "pzl"), ("jqt", "nvd")]
+            .map(|(a, b)| (a.to_string(), b.to_string()));
+        assert_eq!(wires, expected);
+        assert_eq!(sizes.0 + sizes.1, 15);
+        assert_eq!(sizes.0 * sizes.1, 54);
+    }
+}
@@ -0,0 +1,108 @@
+//! The 2023 event.
+
+use crate::solution::Solution;
+
+#[cfg(feature = "y2023-day01")]
+pub mod day01;
+#[cfg(feature = "y2023-day02")]
+pub mod day02;
+#[cfg(feature = "y2023-day03")]
+pub mod day03;
+#[cfg(feature = "y2023-day04")]
+pub mod day04;
+#[cfg(feature = "y2023-day05")]
+pub mod day05;
+#[cfg(feature = "y2023-day06")]
+pub mod day06;
+#[cfg(feature = "y2023-day07")]
+pub mod day07;
+#[cfg(feature = "y2023-day08")]
+pub mod day08;
+#[cfg(feature = "y2023-day09")]
+pub mod day09;
+#[cfg(feature = "y2023-day10")]
+pub mod day10;
+#[cfg(feature = "y2023-day11")]
+pub mod day11;
+#[cfg(feature = "y2023-day12")]
+pub mod day12;
+#[cfg(feature = "y2023-day13")]
+pub mod day13;
+#[cfg(feature = "y2023-day14")]
+pub mod day14;
+#[cfg(feature = "y2023-day15")]
+pub mod day15;
+#[cfg(feature = "y2023-day16")]
+pub mod day16;
+#[cfg(feature = "y2023-day17")]
+pub mod day17;
+#[cfg(feature = "y2023-day18")]
+pub mod day18;
+#[cfg(feature = "y2023-day19")]
+pub mod day19;
+#[cfg(feature = "y2023-day20")]
+pub mod day20;
+#[cfg(feature = "y2023-day21")]
+pub mod day21;
+#[cfg(feature = "y2023-day22")]
+pub mod day22;
+#[cfg(feature = "y2023-day23")]
+pub mod day23;
+#[cfg(feature = "y2023-day24")]
+pub mod day24;
+#[cfg(feature = "y2023-day25")]
+pub mod day25;
+
+/// All solved days, ordered by day.
+pub static DAYS: &[&dyn Solution] = &[
+    #[cfg(feature = "y2023-day01")]
+    &day01::Day01,
+    #[cfg(feature = "y2023-day02")]
+    &day02::Day02,
+    #[cfg(feature = "y2023-day03")]
+    &day03::Day03,
+    #[cfg(feature = "y2023-day04")]
+    &day04::Day04,
+    #[cfg(feature = "y2023-day05")]
+    &day05::Day05,
+    #[cfg(feature = "y2023-day06")]
+    &day06::Day06,
+    #[cfg(feature = "y2023-day07")]
+    &day07::Day07,
+    #[cfg(feature = "y2023-day08")]
+    &day08::Day08,
+    #[cfg(feature = "y2023-day09")]
+    &day09::Day09,
+    #[cfg(feature = "y2023-day10")]
+    &day10::Day10,
+    #[cfg(feature = "y2023-day11")]
+    &day11::Day11,
+    #[cfg(feature = "y2023-day12")]
+    &day12::Day12,
+    #[cfg(feature = "y2023-day13")]
+    &day13::Day13,
+    #[cfg(feature = "y2023-day14")]
+    &day14::Day14,
+    #[cfg(feature = "y2023-day15")]
+    &day15::Day15,
+    #[cfg(feature = "y2023-day16")]
+    &day16::Day16,
+    #[cfg(feature = "y2023-day17")]
+    &day17::Day17,
+    #[cfg(feature = "y2023-day18")]
+    &day18::Day18,
+    #[cfg(feature = "y2023-day19")]
+    &day19::Day19,
+    #[cfg(feature = "y2023-day20")]
+    &day20::Day20,
+    #[cfg(feature = "y2023-day21")]
+    &day21::Day21,
+    #[cfg(feature = "y2023-day22")]
+    &day22::Day22,
+    #[cfg(feature = "y2023-day23")]
+    &day23::Day23,
+    #[cfg(feature = "y2023-day24")]
+    &day24::Day24,
+    #[cfg(feature = "y2023-day25")]
+    &day25::Day25,
+];
@@ -0,0 +1,59 @@
+use std::str::FromStr;
+
+use crate::solution::{Part, Solution};
+
+#[derive(Debug)]
+struct Puzzle {}
+
+impl FromStr for Puzzle {
+    type Err = String;
+
+    fn from_str(_input: &str) -> Result<Self, Self::Err> {
+        todo!()
+    }
+}
+
+fn part_1(_puzzle: &Puzzle) -> u64 {
+    todo!()
+}
+
+fn part_2(_puzzle: &Puzzle) -> u64 {
+    todo!()
+}
+
+pub struct Day{{NN}};
+
+impl Solution for Day{{NN}} {
+    fn day(&self) -> u8 {
+        {{N}}
+    }
+
+    fn part_1(&self, input: &str) -> String {
+        part_1(&input.parse().unwrap()).to_string()
+    }
+
+    fn part_2(&self, input: &str) -> Option<String> {
+        Some(part_2(&input.parse().unwrap()).to_string())
+    }
+
+    fn parse(&self, _part: Part, _input: &str) -> Result<(), String> {
+        // Keeps the fuzz test away from the `todo!()`s, check the input with
+        // `input.parse::<Puzzle>().map(|_| ())` once parsing is done.
+        Err("not implemented yet".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answers::assert_example;
+
+    const EXAMPLE: &str = include_str!("../../../fixtures/{{YEAR}}/day{{NN}}/example.txt");
+    const ANSWERS: &str = include_str!("../../../fixtures/{{YEAR}}/day{{NN}}/answers.txt");
+
+    #[test]
+    #[ignore = "fill in the example from the puzzle description"]
+    fn test_example() {
+        assert_example(&Day{{NN}}, EXAMPLE, ANSWERS);
+    }
+}
@@ -0,0 +1,48 @@
+//! Every explanation has to arrive at the same answer as the solver, see `Solution::explain`.
+//! Checked on the committed puzzle inputs, days without one are skipped.
+
+use std::path::Path;
+
+use aoc::{input, solution::Part, years::YEARS};
+
+/// The days that can explain both of their answers, by year, so a day that stops explaining
+/// doesn't go unnoticed.
+const EXPLAINED: &[(u16, &[u8])] = &[(2023, &[1, 2, 7, 12, 13, 17, 19])];
+
+#[test]
+fn explanations_lead_to_the_answer() {
+    for year in YEARS {
+        let explained = EXPLAINED
+            .iter()
+            .find(|(explained, _)| *explained == year.year)
+            .map_or(&[][..], |(_, days)| *days);
+        for solution in year.days {
+            let path =
+                Path::new(env!("CARGO_MANIFEST_DIR")).join(input::path(year.year, solution.day()));
+            let Ok(input) = input::read(&path) else {
+                continue;
+            };
+            for part in Part::ALL {
+                let Some(trace) = solution.explain(part, &input) else {
+                    assert!(
+                        !explained.contains(&solution.day()),
+                        "day {:02} part {} of {} explains nothing",
+                        solution.day(),
+                        part,
+                        year.year
+                    );
+                    continue;
+                };
+                assert_eq!(
+                    trace.value,
+                    solution.solve(part, &input),
+                    "day {:02} part {} of {} explains a different answer",
+                    solution.day(),
+                    part,
+                    year.year
+                );
+                assert!(!trace.steps.is_empty());
+            }
+        }
+    }
+}
@@ -0,0 +1,121 @@
+// The tests solve the examples of days 1 and 21 of 2023.
+#![cfg(all(feature = "ffi", feature = "y2023-day01", feature = "y2023-day21"))]
+
+use std::{
+    env::consts::{DLL_PREFIX, DLL_SUFFIX},
+    ffi::{c_char, CStr},
+};
+
+use libloading::{Library, Symbol};
+
+type Solve = unsafe extern "C" fn(u16, u8, u8, *const u8, usize, *mut c_char, *mut usize) -> i32;
+type StatusMessage = unsafe extern "C" fn(i32) -> *const c_char;
+
+const EXAMPLE: &str = "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n";
+
+/// `cargo test` builds the cdylib into `target/<profile>/deps`, next to the test binary. Fall back
+/// to `target/<profile>` where `cargo build` puts it.
+fn library() -> Library {
+    let exe = std::env::current_exe().unwrap();
+    let name = format!("{}aoc{}", DLL_PREFIX, DLL_SUFFIX);
+    let deps = exe.parent().unwrap();
+    let path = [deps.join(&name), deps.parent().unwrap().join(&name)]
+        .into_iter()
+        .find(|p| p.exists())
+        .unwrap_or_else(|| panic!("{} wasn't built", name));
+
+    unsafe { Library::new(&path) }.unwrap_or_else(|e| panic!("{}: {}", path.display(), e))
+}
+
+fn solve(
+    library: &Library,
+    year: u16,
+    day: u8,
+    part: u8,
+    input: &[u8],
+    capacity: usize,
+) -> (i32, String) {
+    let solve: Symbol<Solve> = unsafe { library.get(b"aoc_solve") }.unwrap();
+    let mut out = vec![0 as c_char; capacity];
+    let mut out_len = out.len();
+
+    let status = unsafe {
+        solve(
+            year,
+            day,
+            part,
+            input.as_ptr(),
+            input.len(),
+            out.as_mut_ptr(),
+            &mut out_len,
+        )
+    };
+    let answer = if status == 0 {
+        unsafe { CStr::from_ptr(out.as_ptr()) }
+            .to_str()
+            .unwrap()
+            .to_string()
+    } else {
+        out_len.to_string()
+    };
+
+    (status, answer)
+}
+
+#[test]
+fn test_solve() {
+    let library = library();
+
+    assert_eq!(
+        solve(&library, 2023, 1, 1, EXAMPLE.as_bytes(), 64),
+        (0, "142".to_string())
+    );
+    // CRLF is normalised like inputs loaded from files.
+    let crlf = EXAMPLE.replace('\n', "\r\n");
+    assert_eq!(
+        solve(&library, 2023, 1, 1, crlf.as_bytes(), 64),
+        (0, "142".to_string())
+    );
+}
+
+#[test]
+fn test_errors() {
+    let library = library();
+
+    assert_eq!(solve(&library, 2023, 26, 1, EXAMPLE.as_bytes(), 64).0, 1);
+    assert_eq!(solve(&library, 2015, 1, 1, EXAMPLE.as_bytes(), 64).0, 8);
+    assert_eq!(solve(&library, 2023, 1, 3, EXAMPLE.as_bytes(), 64).0, 2);
+    assert_eq!(solve(&library, 2023, 21, 2, b"S", 64).0, 3);
+    assert_eq!(solve(&library, 2023, 1, 1, &[0xff, 0xfe], 64).0, 4);
+    assert_eq!(solve(&library, 2023, 21, 1, b"no start", 64).0, 5);
+    // The answer "142" needs 4 bytes including the NUL byte, the required length is returned.
+    assert_eq!(
+        solve(&library, 2023, 1, 1, EXAMPLE.as_bytes(), 3),
+        (6, "3".to_string())
+    );
+}
+
+#[test]
+fn test_null_input_without_bytes() {
+    let library = library();
+    let solve: Symbol<Solve> = unsafe { library.get(b"aoc_solve") }.unwrap();
+    let mut out = [0 as c_char; 8];
+    let mut out_len = out.len();
+
+    let call = |len, out: &mut [c_char], out_len: &mut usize| unsafe {
+        solve(2023, 1, 1, std::ptr::null(), len, out.as_mut_ptr(), out_len)
+    };
+    assert_eq!(call(1, &mut out, &mut out_len), 7);
+    assert_eq!(call(0, &mut out, &mut out_len), 0);
+    assert_eq!(unsafe { CStr::from_ptr(out.as_ptr()) }.to_str(), Ok("0"));
+}
+
+#[test]
+fn test_status_message() {
+    let library = library();
+    let message: Symbol<StatusMessage> = unsafe { library.get(b"aoc_status_message") }.unwrap();
+
+    let text = |status| unsafe { CStr::from_ptr(message(status)) }.to_str().unwrap();
+    assert_eq!(text(6), "output buffer too small");
+    assert_eq!(text(42), "unknown status");
+}
@@ -0,0 +1,244 @@
+//! Throws random and mutated inputs at every day. Parsing has to either succeed or fail with an
+//! error, see `Solution::parse`, and inputs that parse have to be solved without panicking. Giving
+//! up on an input by running out of time or memory is fine, it's what the limits are for.
+//!
+//! The inputs are mutations of the examples in `fixtures/` and of the start of the committed
+//! puzzle inputs, drawn from a seeded generator so failures can be reproduced. Set
+//! `AOC_FUZZ_SEED` to try other inputs and `AOC_FUZZ_ROUNDS` to try more of them per day.
+
+use std::{
+    env, fs,
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::Duration,
+};
+
+use aoc::{
+    alloc::CountingAllocator,
+    input,
+    runner::{self, Limits, Outcome},
+    solution::{Part, Solution},
+    years::YEARS,
+};
+
+// Needed for the memory limit of the runner.
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Mutated inputs can ask for a lot, e.g. by turning a count into a huge number.
+const LIMITS: Limits = Limits {
+    time: Duration::from_millis(500),
+    memory: Some(256 << 20),
+};
+
+/// SplitMix64, which is plenty for picking mutations and keeps the test free of dependencies.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..n`, `n` must not be zero.
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+
+    fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+}
+
+/// Characters that mean something to at least one parser, plus some that mean nothing to any.
+const SPECIALS: &[char] = &[
+    '\n', ' ', ',', ':', ';', '=', '-', '~', '@', '{', '}', '(', ')', '#', '.', '0', '9', 'A', 'S',
+    'é', '💥', '\t', '\0',
+];
+
+fn env_or(name: &str, default: u64) -> u64 {
+    env::var(name).map_or(default, |value| {
+        value
+            .parse()
+            .unwrap_or_else(|_| panic!("{} must be a number", name))
+    })
+}
+
+fn fixtures(year: u16, day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join(format!("fixtures/{}/day{:02}", year, day))
+}
+
+/// The examples of a day, `example.txt`, `example-2.txt` and so on. Empty ones, like the
+/// placeholders written by `aoc new`, are left out.
+fn examples(year: u16, day: u8) -> Vec<String> {
+    let Ok(entries) = fs::read_dir(fixtures(year, day)) else {
+        return vec![];
+    };
+    let mut paths = entries
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| {
+            let name = path.file_name().unwrap().to_string_lossy();
+            name.starts_with("example") && name.ends_with(".txt")
+        })
+        .collect::<Vec<_>>();
+    paths.sort();
+    paths
+        .iter()
+        .map(|path| fs::read_to_string(path).unwrap())
+        .filter(|example| !example.trim().is_empty())
+        .collect()
+}
+
+/// The first lines of every block of the puzzle input, if there is one that can be read. Whole
+/// inputs are large enough to make thousands of parses slow.
+fn input_start(year: u16, day: u8) -> Option<String> {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(input::path(year, day));
+    let input = input::read(&path).ok()?;
+    let blocks = input::blocks(&input)
+        .map(|block| block.lines().take(10).collect::<Vec<_>>().join("\n"))
+        .collect::<Vec<_>>();
+    Some(blocks.join("\n\n") + "\n")
+}
+
+/// Applies a random edit to `input`. Edits work on characters so the result stays valid UTF-8.
+fn mutate(rng: &mut Rng, input: &mut Vec<char>) {
+    let len = input.len();
+    let position = rng.below(len + 1);
+    let end = (position + 1 + rng.below(8)).min(len);
+    match rng.below(10) {
+        0 if position < len => {
+            input.drain(position..end);
+        }
+        1 if position < len => {
+            // Drop the whole word or number, leaving the separators around it.
+            let word = |c: &char| c.is_alphanumeric();
+            let start = input[..position]
+                .iter()
+                .rposition(|c| !word(c))
+                .map_or(0, |i| i + 1);
+            let end = input[position..]
+                .iter()
+                .position(|c| !word(c))
+                .map_or(len, |i| position + i);
+            input.drain(start..end);
+        }
+        2 => input.insert(position, *rng.pick(SPECIALS)),
+        3 if position < len => input[position] = *rng.pick(SPECIALS),
+        4 if position < len => {
+            let copy = input[position..end].to_vec();
+            input.splice(position..position, copy);
+        }
+        5 => input.truncate(position),
+        6 => {
+            input.splice(position..position, "99999999999999999999999".chars());
+        }
+        7 | 8 => {
+            let text = input.iter().collect::<String>();
+            let mut lines = text.split('\n').collect::<Vec<_>>();
+            let line = rng.below(lines.len());
+            if rng.below(2) == 0 {
+                lines.remove(line);
+            } else {
+                let other = rng.below(lines.len());
+                lines.swap(line, other);
+            }
+            *input = lines.join("\n").chars().collect();
+        }
+        _ => {
+            // Swap in a character from elsewhere in the input, which keeps it plausible.
+            if position < len {
+                input[position] = input[rng.below(len)];
+            }
+        }
+    }
+}
+
+/// Solves every part that `input` parses for, turning a panic into an error message naming the
+/// input.
+fn check(solution: &'static dyn Solution, input: &str) -> Result<(), String> {
+    let day = solution.day();
+    let input = Arc::<str>::from(input);
+    for part in Part::ALL {
+        match panic::catch_unwind(AssertUnwindSafe(|| solution.parse(part, &input))) {
+            Ok(Ok(())) => {}
+            Ok(Err(_)) => continue,
+            Err(_) => {
+                return Err(format!(
+                    "day {:02} part {} failed to parse {:?}",
+                    day, part, input
+                ))
+            }
+        }
+        if let Outcome::Panicked(message) =
+            runner::run_part(solution, part, input.clone(), &LIMITS).outcome
+        {
+            return Err(format!(
+                "day {:02} part {} panicked with '{}' on {:?}",
+                day, part, message, input
+            ));
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn days_never_panic() {
+    // The failures are collected below, the default hook would print every one of them.
+    panic::set_hook(Box::new(|_| {}));
+
+    let seed = env_or("AOC_FUZZ_SEED", 2023);
+    let rounds = env_or("AOC_FUZZ_ROUNDS", 200);
+
+    let mut failures = vec![];
+    for year in YEARS {
+        for solution in year.days {
+            let mut rng = Rng(seed ^ (year.year as u64) << 8 ^ solution.day() as u64);
+
+            let examples = examples(year.year, solution.day());
+            for example in examples.iter() {
+                // Examples can be meant for one part only, like the second one of day 1.
+                assert!(
+                    Part::ALL
+                        .iter()
+                        .any(|&part| solution.parse(part, example).is_ok()),
+                    "day {:02} rejects its example",
+                    solution.day()
+                );
+            }
+            let seeds = examples
+                .into_iter()
+                .chain(input_start(year.year, solution.day()))
+                .collect::<Vec<_>>();
+
+            for _ in 0..rounds {
+                let input = if seeds.is_empty() || rng.below(8) == 0 {
+                    (0..rng.below(64)).map(|_| *rng.pick(SPECIALS)).collect()
+                } else {
+                    let mut input = rng.pick(&seeds).chars().collect();
+                    for _ in 0..=rng.below(4) {
+                        mutate(&mut rng, &mut input);
+                    }
+                    input.into_iter().collect::<String>()
+                };
+
+                if let Err(failure) = check(*solution, &input) {
+                    failures.push(failure);
+                    // One input is enough to go on, the rest are likely the same bug.
+                    break;
+                }
+            }
+        }
+    }
+
+    drop(panic::take_hook());
+    assert!(
+        failures.is_empty(),
+        "days panicked with seed {}:\n{}",
+        seed,
+        failures.join("\n")
+    );
+}